@@ -14,7 +14,7 @@ fn App(cx: Scope) -> Element {
         label {
             input {
                 r#type: "checkbox",
-                checked: "{enable_directory_upload}",
+                checked: **enable_directory_upload,
                 oninput: move |evt| {
                     enable_directory_upload.set(evt.value.parse().unwrap());
                 },
@@ -65,7 +65,7 @@ pub fn app(cx: Scope<()>) -> Element {
                                     item.checked = check;
                                 }
                             },
-                            checked: if active_todo_count == 0 { "true" } else { "false" },
+                            checked: active_todo_count == 0,
                         }
                         label { r#for: "toggle-all" }
                     }
@@ -153,7 +153,7 @@ pub fn TodoEntry<'a>(cx: Scope<'a, TodoEntryProps<'a>>) -> Element {
                     class: "toggle",
                     r#type: "checkbox",
                     id: "cbg-{todo.id}",
-                    checked: "{todo.checked}",
+                    checked: todo.checked,
                     oninput: move |evt| {
                         cx.props.todos.make_mut()[&cx.props.id].checked = evt.value.parse().unwrap();
                     }
@@ -150,10 +150,15 @@ pub use utils::DeserializerOutput;
 pub use utils::TypeHelper;
 
 use dioxus_core::{Element, Scope};
-use syn::parse::{Parse, ParseStream};
+use proc_macro2::{Delimiter, TokenStream as TokenStream2, TokenTree};
+use quote::ToTokens;
+use syn::parse::{Parse, ParseStream, Parser};
 use syn::spanned::Spanned;
 use syn::*;
 
+/// The error for a `self` parameter, which usually means a method was pasted into a component.
+const RECEIVER_ERROR: &str = "Components are free functions and cannot take `self`";
+
 /// General struct for parsing a component body.
 /// However, because it's ambiguous, it does not implement [`ToTokens`](quote::to_tokens::ToTokens).
 ///
@@ -191,18 +196,30 @@ impl ComponentBody {
 
 impl Parse for ComponentBody {
     fn parse(input: ParseStream) -> Result<Self> {
-        let item_fn: ItemFn = input.parse()?;
+        let mut item_fn: ItemFn = input.parse()?;
         let scope_type_path = Scope::get_path_string();
 
-        let (cx_arg, cx_pat_type) = if let Some(first_arg) = item_fn.sig.inputs.first() {
-            let incorrect_first_arg_err = Err(Error::new(
-                first_arg.span(),
-                format!("First argument must be a <{}>", scope_type_path),
+        // Components are only ever called while rendering, and hooks and `render!` can't run in a `const` context.
+        if let Some(const_token) = &item_fn.sig.constness {
+            return Err(Error::new(
+                const_token.span(),
+                "Components can't be `const`, since they run at render time. Remove the `const`",
+            ));
+        }
+
+        // The props struct borrows from the scope with the component's lifetime, so there's only room for one.
+        if let Some(extra) = item_fn.sig.generics.lifetimes().nth(1) {
+            return Err(Error::new(
+                extra.lifetime.span(),
+                "Components can have at most one lifetime, which the props borrow from the scope, \
+like `fn TodoEntry<'a>(cx: Scope<'a>, todos: &'a UseState<Todos>) -> Element`",
             ));
+        }
 
+        let (cx_arg, cx_pat_type) = if let Some(first_arg) = item_fn.sig.inputs.first() {
             match first_arg.to_owned() {
-                FnArg::Receiver(_) => {
-                    return incorrect_first_arg_err;
+                FnArg::Receiver(recv) => {
+                    return Err(Error::new(recv.span(), RECEIVER_ERROR));
                 }
                 FnArg::Typed(f) => (first_arg.to_owned(), f),
             }
@@ -218,13 +235,57 @@ impl Parse for ComponentBody {
 
         let element_type_path = Element::get_path_string();
 
-        if item_fn.sig.output == ReturnType::Default {
-            return Err(Error::new(
-                item_fn.sig.output.span(),
-                format!("Must return a <{}>", element_type_path),
-            ));
+        let output_ty = match &item_fn.sig.output {
+            ReturnType::Default => {
+                return Err(Error::new(
+                    item_fn.sig.output.span(),
+                    format!("Must return a <{}>", element_type_path),
+                ));
+            }
+            ReturnType::Type(_, ty) => ty,
+        };
+
+        if let Some(msg) = get_wrong_return_type_msg(output_ty, &element_type_path) {
+            return Err(Error::new(output_ty.span(), msg));
         }
 
+        // Every extra argument becomes a field of the props struct, so it has to be named by a plain identifier.
+        for arg in item_fn.sig.inputs.iter().skip(1) {
+            let FnArg::Typed(pt) = arg else {
+                return Err(Error::new(arg.span(), RECEIVER_ERROR));
+            };
+
+            match &*pt.pat {
+                Pat::Ident(_) => {}
+                Pat::Tuple(_) | Pat::TupleStruct(_) => {
+                    return Err(Error::new(
+                        pt.pat.span(),
+                        "Tuple patterns aren't supported for props. \
+Give the prop a name, like `pair: (String, String)`, and destructure it in the component body",
+                    ));
+                }
+                _ => {
+                    return Err(Error::new(
+                        pt.pat.span(),
+                        "Props must be named by a plain identifier, like `title: String`",
+                    ));
+                }
+            }
+
+            // The props struct can't infer the type of a field, and rustc's own error would point at the struct.
+            if let Type::Infer(_) = &*pt.ty {
+                return Err(Error::new(
+                    pt.span(),
+                    format!(
+                        "Component props must be typed: `{}: Type`",
+                        pt.pat.to_token_stream()
+                    ),
+                ));
+            }
+        }
+
+        lift_impl_trait_args(&mut item_fn.sig);
+
         let has_extra_args = item_fn.sig.inputs.len() > 1;
 
         Ok(Self {
@@ -235,3 +296,114 @@ impl Parse for ComponentBody {
         })
     }
 }
+
+/// Checks for an argument without a type, like the `label` in `fn Label(cx: Scope, label)`.
+///
+/// rustc rejects these itself before expanding the macro, with a suggestion to add the type, so the macro expands
+/// to nothing for them instead of repeating the error. Every other argument has to parse, so broken arguments
+/// still get the usual error.
+pub(crate) fn has_untyped_arg(tokens: TokenStream2) -> bool {
+    // The arguments are the first parenthesized group after `fn` that isn't in the generics, like the `(u8)` in
+    // `fn Foo<F: Fn(u8)>`. A `>` right after a `-` is the arrow of a return type, not the end of the generics.
+    let mut after_fn = false;
+    let mut generics_depth = 0;
+    let mut prev_is_dash = false;
+    let mut args = None;
+
+    for tt in tokens {
+        match &tt {
+            TokenTree::Ident(ident) if ident == "fn" => after_fn = true,
+            TokenTree::Punct(punct) if after_fn && punct.as_char() == '<' => generics_depth += 1,
+            TokenTree::Punct(punct) if after_fn && punct.as_char() == '>' && !prev_is_dash => {
+                generics_depth -= 1
+            }
+            TokenTree::Group(group)
+                if after_fn
+                    && generics_depth == 0
+                    && group.delimiter() == Delimiter::Parenthesis =>
+            {
+                args = Some(group.stream());
+                break;
+            }
+            _ => {}
+        }
+
+        prev_is_dash = matches!(&tt, TokenTree::Punct(punct) if punct.as_char() == '-');
+    }
+
+    let Some(args) = args else {
+        return false;
+    };
+
+    let parser = |input: ParseStream| -> Result<bool> {
+        while !input.is_empty() {
+            Attribute::parse_outer(input)?;
+            Pat::parse_single(input)?;
+
+            if input.is_empty() || input.peek(Token![,]) {
+                return Ok(true);
+            }
+
+            input.parse::<Token![:]>()?;
+            input.parse::<Type>()?;
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(false)
+    };
+
+    parser.parse2(args).unwrap_or(false)
+}
+
+/// Returns an error message if the return type is clearly not an [`Element`].
+///
+/// Type aliases and unknown paths can't be resolved here, so only obvious mismatches are caught:
+/// scalars like `String` or `bool`, references, tuples and `Result`s.
+fn get_wrong_return_type_msg(ty: &Type, element_type_path: &str) -> Option<String> {
+    let is_result = match ty {
+        Type::Path(type_path) => match type_path.path.segments.last() {
+            Some(segment) => segment.ident == "Result",
+            None => false,
+        },
+        _ => false,
+    };
+
+    if is_result {
+        Some(format!(
+            "Components can't return a `Result`. Return a <{}> and use `Throw::throw` to pass errors to an error boundary",
+            element_type_path
+        ))
+    } else if crate::utils::is_scalar_type(ty) || matches!(ty, Type::Reference(_) | Type::Tuple(_))
+    {
+        Some(format!(
+            "Must return a <{}>, like `fn MyComponent(cx: Scope) -> Element`",
+            element_type_path
+        ))
+    } else {
+        None
+    }
+}
+
+/// Replaces the `impl Trait` types of props with generic parameters with the same bounds,
+/// like `items: impl IntoIterator<Item = String>` with `items: __Impl1` and `__Impl1: IntoIterator<Item = String>`.
+///
+/// Every prop becomes a field of the props struct, and `impl Trait` isn't allowed in a field type.
+/// The parameter is named after the argument's position, so each `impl Trait` prop can still be a different type.
+fn lift_impl_trait_args(sig: &mut Signature) {
+    for (index, arg) in sig.inputs.iter_mut().enumerate().skip(1) {
+        let FnArg::Typed(pt) = arg else {
+            continue;
+        };
+        let Type::ImplTrait(impl_trait) = &*pt.ty else {
+            continue;
+        };
+
+        let ident = Ident::new(&format!("__Impl{index}"), impl_trait.span());
+        let bounds = &impl_trait.bounds;
+        sig.generics.params.push(parse_quote!(#ident: #bounds));
+        pt.ty = parse_quote!(#ident);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::component_body::ComponentBody;
-use dioxus_core::{Element, Scope};
+use dioxus_core::{Element, EventHandler, Scope};
 use quote::ToTokens;
 use syn::{parse_quote, Path};
 
@@ -55,3 +55,9 @@ impl<'a> TypeHelper for Element<'a> {
         parse_quote!(::dioxus::core::Element)
     }
 }
+
+impl<'a, T> TypeHelper for EventHandler<'a, T> {
+    fn get_path() -> Path {
+        parse_quote!(::dioxus::core::EventHandler)
+    }
+}
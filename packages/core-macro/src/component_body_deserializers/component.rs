@@ -1,8 +1,16 @@
 use crate::component_body::{ComponentBody, DeserializerArgs};
-use crate::component_body_deserializers::inline_props::InlinePropsDeserializerArgs;
+use crate::component_body_deserializers::inline_props::{
+    get_cfg_attrs, get_placeholder_value, get_prop_field_ident, get_prop_ident, get_props_args,
+    is_prop_required, tokens_contain_ident, InlinePropsDeserializerArgs,
+};
+use crate::component_body_deserializers::no_memo::NoMemoDeserializerArgs;
 use constcat::concat;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::{quote, ToTokens, TokenStreamExt};
+use quote::{format_ident, quote, ToTokens, TokenStreamExt};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
 use syn::*;
 
 pub(crate) const COMPONENT_ARG_CASE_CHECK_ERROR: &str = concat!(
@@ -15,11 +23,26 @@ To ignore this check, pass the \"",
 );
 
 const INNER_FN_NAME: &str = "__dx_inner_comp";
+/// Attributes that only make sense on the component function itself, so they aren't copied to the inner function.
+/// The inner function is always `#[inline(always)]`, which conflicts with `#[inline]` and `#[cold]`, and
+/// `#[must_use]` is only checked where the component is called.
+const OUTER_FN_ONLY_ATTRS: [&str; 3] = ["inline", "cold", "must_use"];
+pub(crate) const CONTEXT_ARG_ATTR: &str = "from_context";
 
 fn get_out_comp_fn(orig_comp_fn: &ItemFn, cx_pat: &Pat) -> ItemFn {
     let inner_comp_ident = Ident::new(INNER_FN_NAME, orig_comp_fn.sig.ident.span());
 
     let inner_comp_fn = ItemFn {
+        attrs: orig_comp_fn
+            .attrs
+            .iter()
+            .filter(|attr| {
+                !OUTER_FN_ONLY_ATTRS
+                    .iter()
+                    .any(|name| attr.path().is_ident(name))
+            })
+            .cloned()
+            .collect(),
         sig: Signature {
             ident: inner_comp_ident.clone(),
             ..orig_comp_fn.sig.clone()
@@ -41,10 +64,915 @@ fn get_out_comp_fn(orig_comp_fn: &ItemFn, cx_pat: &Pat) -> ItemFn {
     }
 }
 
+/// Whether the argument is the component's `key`, which isn't a prop, see [`inject_key_arg`].
+///
+/// Renaming the argument to something else with `#[props(rename = "...")]` makes it a regular prop again.
+pub(crate) fn is_key_arg(pt: &PatType) -> bool {
+    get_prop_ident(pt).unraw() == "key" && get_prop_field_ident(pt).unraw() == "key"
+}
+
+/// Removes the `key` argument from the component, and binds it to [`ScopeState::key`](dioxus_core::ScopeState::key)
+/// at the start of its body instead.
+///
+/// `rsx!` never passes `key` to the props builder, it's the key of the component in keyed lists, so it can't be a prop.
+/// The key is only there if the component was created keyed, so the argument should be an `Option<&str>`.
+fn inject_key_arg(component_body: &ComponentBody) -> Result<ComponentBody> {
+    let ComponentBody {
+        item_fn,
+        cx_pat_type,
+        ..
+    } = component_body;
+    let cx_pat = &cx_pat_type.pat;
+
+    let mut inputs = Punctuated::<FnArg, Token![,]>::new();
+    let mut key_read = None;
+
+    for (idx, arg) in item_fn.sig.inputs.iter().enumerate() {
+        match arg {
+            FnArg::Typed(pt) if idx > 0 && is_key_arg(pt) => {
+                let PatType { pat, ty, .. } = pt;
+                key_read = Some(quote! {
+                    let #pat: #ty = #cx_pat.key();
+                });
+            }
+            _ => inputs.push(arg.clone()),
+        }
+    }
+
+    let Some(key_read) = key_read else {
+        return parse2(quote!(#item_fn));
+    };
+
+    let block = &item_fn.block;
+    let item_fn = ItemFn {
+        sig: Signature {
+            inputs,
+            ..item_fn.sig.clone()
+        },
+        block: parse_quote! {
+            {
+                #key_read
+                #block
+            }
+        },
+        ..item_fn.clone()
+    };
+
+    parse2(quote!(#item_fn))
+}
+
+/// Removes the arguments marked with `#[from_context]` from the component, and reads them from the
+/// context at the start of its body instead, so they don't end up in the props struct.
+///
+/// A missing context panics, naming the type and the argument, since there's no value to fall back to.
+fn inject_context_args(component_body: &ComponentBody) -> Result<ComponentBody> {
+    let ComponentBody {
+        item_fn,
+        cx_pat_type,
+        ..
+    } = component_body;
+    let cx_pat = &cx_pat_type.pat;
+    let fn_ident = &item_fn.sig.ident;
+
+    let mut inputs = Punctuated::<FnArg, Token![,]>::new();
+    let mut context_reads = Vec::new();
+
+    for arg in item_fn.sig.inputs.iter() {
+        let FnArg::Typed(pt) = arg else {
+            inputs.push(arg.clone());
+            continue;
+        };
+
+        if !pt
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident(CONTEXT_ARG_ATTR))
+        {
+            inputs.push(arg.clone());
+            continue;
+        }
+
+        let PatType { pat, ty, .. } = pt;
+        let msg = format!(
+            "No context of type `{}` was provided for the `{}` argument of `{fn_ident}`",
+            ty.to_token_stream(),
+            get_prop_ident(pt),
+        );
+
+        context_reads.push(quote! {
+            let #pat: #ty = #cx_pat.consume_context::<#ty>().expect(#msg);
+        });
+    }
+
+    if context_reads.is_empty() {
+        return parse2(quote!(#item_fn));
+    }
+
+    let block = &item_fn.block;
+    let item_fn = ItemFn {
+        sig: Signature {
+            inputs,
+            ..item_fn.sig.clone()
+        },
+        block: parse_quote! {
+            {
+                #(#context_reads)*
+                #block
+            }
+        },
+        ..item_fn.clone()
+    };
+
+    parse2(quote!(#item_fn))
+}
+
+/// Turns a component that returns `Option<Element>` into one that returns `Element`, where `None` renders nothing.
+///
+/// The body is moved into a closure, so `return` and `?` keep working on the `Option`.
+/// Components returning anything else are left as they are.
+fn flatten_option_return(component_body: &ComponentBody) -> Result<ComponentBody> {
+    let item_fn = &component_body.item_fn;
+
+    let Some(element_ty) = get_option_element_type(&item_fn.sig.output) else {
+        return parse2(quote!(#item_fn));
+    };
+
+    let block = &item_fn.block;
+    let item_fn = ItemFn {
+        sig: Signature {
+            output: parse_quote!(-> #element_ty),
+            ..item_fn.sig.clone()
+        },
+        block: parse_quote! {
+            {
+                ::core::option::Option::flatten((move || -> ::core::option::Option<#element_ty> #block)())
+            }
+        },
+        ..item_fn.clone()
+    };
+
+    parse2(quote!(#item_fn))
+}
+
+/// Turns an `async` component into a synchronous one that renders its body with
+/// [`ScopeState::render_async`](dioxus_core::ScopeState::render_async).
+///
+/// The component suspends while the body is pending, so the nearest suspense boundary shows its placeholder,
+/// and it's rendered again when the body is woken. A new future is polled on every render, since it borrows
+/// the scope. An `Option<Element>` return type is flattened inside the future, so `?` keeps working on `Option`s.
+fn wrap_async_body(component_body: &ComponentBody) -> Result<ComponentBody> {
+    let ComponentBody {
+        item_fn,
+        cx_pat_type,
+        ..
+    } = component_body;
+
+    if item_fn.sig.asyncness.is_none() {
+        return parse2(quote!(#item_fn));
+    }
+
+    let cx_ident = match &*cx_pat_type.pat {
+        Pat::Ident(pat_ident) => &pat_ident.ident,
+        pat => return Err(Error::new(
+            pat.span(),
+            "Async components render through their scope, so it has to be named, like `cx: Scope`",
+        )),
+    };
+
+    let block = &item_fn.block;
+    let (output, future) = match get_option_element_type(&item_fn.sig.output) {
+        Some(element_ty) => (
+            parse_quote!(-> #element_ty),
+            quote! {
+                async move {
+                    let element: ::core::option::Option<#element_ty> = async move #block.await;
+                    ::core::option::Option::flatten(element)
+                }
+            },
+        ),
+        None => (item_fn.sig.output.clone(), quote!(async move #block)),
+    };
+
+    let item_fn = ItemFn {
+        sig: Signature {
+            asyncness: None,
+            output,
+            ..item_fn.sig.clone()
+        },
+        block: parse_quote! {
+            {
+                #cx_ident.render_async(#future)
+            }
+        },
+        ..item_fn.clone()
+    };
+
+    parse2(quote!(#item_fn))
+}
+
+/// Returns the `Element` in an `Option<Element>` return type.
+fn get_option_element_type(output: &ReturnType) -> Option<&Type> {
+    let ReturnType::Type(_, ty) = output else {
+        return None;
+    };
+    let Type::Path(type_path) = &**ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+
+    match args.args.first() {
+        Some(GenericArgument::Type(inner @ Type::Path(inner_path)))
+            if args.args.len() == 1
+                && inner_path
+                    .path
+                    .segments
+                    .last()
+                    .is_some_and(|segment| segment.ident == "Element") =>
+        {
+            Some(inner)
+        }
+        _ => None,
+    }
+}
+
+/// The name of the marker struct of a component, like `__dioxus_component_marker_Home` for `Home`.
+///
+/// It's mangled so it never clashes with a type the user already has, and the items generated on the marker
+/// always find it, even if another type with the component's name is in scope.
+pub(crate) fn get_marker_ident(comp_ident: &Ident) -> Ident {
+    format_ident!("__dioxus_component_marker_{}", comp_ident)
+}
+
+/// Creates a zero-sized marker struct for the component, along with impls that make it `Copy` and
+/// dereference to the component function pointer.
+///
+/// The struct itself has a mangled name from [`get_marker_ident`]. With the `marker` argument, it's also
+/// exposed under the component's name by a type alias, which only lives in the type namespace, so it doesn't
+/// collide with the function itself. Only non-generic components get a marker, since a generic component
+/// doesn't have a single function pointer to forward to.
+fn get_marker(
+    orig_comp_fn: &ItemFn,
+    props_ty: Type,
+    expose: bool,
+) -> Option<(ItemStruct, ItemImpl, Option<ItemType>)> {
+    let ItemFn { vis, sig, .. } = orig_comp_fn;
+
+    if !sig.generics.params.is_empty() {
+        return None;
+    }
+
+    let ident = &sig.ident;
+    let marker_ident = get_marker_ident(ident);
+    let doc = format!("A copyable marker for the [`{ident}`](fn@{ident}) component. Dereferences to the component function.");
+
+    let marker_struct = parse_quote! {
+        #[doc(hidden)]
+        #[allow(non_camel_case_types)]
+        #[derive(
+            ::core::clone::Clone,
+            ::core::marker::Copy,
+            ::core::fmt::Debug,
+            ::core::default::Default,
+            ::core::cmp::PartialEq,
+            ::core::cmp::Eq,
+        )]
+        #vis struct #marker_ident {}
+    };
+
+    let marker_impl = parse_quote! {
+        impl ::core::ops::Deref for #marker_ident {
+            type Target = ::dioxus::core::Component<#props_ty>;
+
+            fn deref(&self) -> &Self::Target {
+                const COMPONENT: ::dioxus::core::Component<#props_ty> = #ident;
+                &COMPONENT
+            }
+        }
+    };
+
+    let marker_alias = expose.then(|| {
+        parse_quote! {
+            #[doc = #doc]
+            #[allow(non_camel_case_types)]
+            #vis type #ident = #marker_ident;
+        }
+    });
+
+    Some((marker_struct, marker_impl, marker_alias))
+}
+
+/// Returns the props type of a context argument like `cx: Scope<'a, LabelProps>`, or `None` for a `Scope`
+/// without props, including `Scope<()>`.
+fn get_explicit_props_type(cx_pat_type: &PatType) -> Option<&Type> {
+    let Type::Path(type_path) = &*cx_pat_type.ty else {
+        return None;
+    };
+    let PathArguments::AngleBracketed(args) = &type_path.path.segments.last()?.arguments else {
+        return None;
+    };
+
+    args.args.iter().find_map(|arg| match arg {
+        GenericArgument::Type(Type::Tuple(tuple)) if tuple.elems.is_empty() => None,
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    })
+}
+
+/// The name that the hand-written props of a component with both a props type in its `Scope` and prop arguments
+/// are bound to in the body, before the `Scope` is rebuilt around them.
+const EXPLICIT_PROPS_BINDING: &str = "__explicit_props";
+
+/// Turns the props type of a `Scope`, like `cx: Scope<CardProps>`, into a `props` prop of the component, for
+/// components that also have prop arguments.
+///
+/// The body still gets a `Scope<CardProps>`: it's rebuilt at the start of every render from the scope and the
+/// `props` prop, so `cx.props` keeps working like it would without the macro, and the other arguments are
+/// regular props.
+fn move_explicit_props_into_args(
+    item_fn: &ItemFn,
+    cx_pat_type: &PatType,
+    props_ty: &Type,
+) -> Result<ItemFn> {
+    let mut item_fn = item_fn.clone();
+    let Pat::Ident(PatIdent {
+        ident: cx_ident, ..
+    }) = &*cx_pat_type.pat
+    else {
+        return Err(Error::new(
+            cx_pat_type.pat.span(),
+            "Expected an identifier, like `cx`, since the `Scope` is rebuilt around its props type",
+        ));
+    };
+    let cx_ty = &cx_pat_type.ty;
+    let binding = Ident::new(EXPLICIT_PROPS_BINDING, props_ty.span());
+
+    // Only the props type is taken out, so a named lifetime, like in `Scope<'a, CardProps<'a>>`, stays.
+    let mut scope_ty = (**cx_ty).clone();
+    if let Type::Path(type_path) = &mut scope_ty {
+        if let Some(segment) = type_path.path.segments.last_mut() {
+            if let PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                args.args = std::mem::take(&mut args.args)
+                    .into_iter()
+                    .filter(|arg| !matches!(arg, GenericArgument::Type(_)))
+                    .collect();
+                if args.args.is_empty() {
+                    segment.arguments = PathArguments::None;
+                }
+            }
+        }
+    }
+
+    let mut scope_arg = cx_pat_type.clone();
+    scope_arg.ty = Box::new(scope_ty);
+    item_fn.sig.inputs[0] = FnArg::Typed(scope_arg);
+    item_fn.sig.inputs.insert(
+        1,
+        parse_quote!(#[props(rename = "props")] #binding: #props_ty),
+    );
+    item_fn.block.stmts.insert(
+        0,
+        parse_quote! {
+            let #cx_ident: #cx_ty = #cx_ident.bump().alloc(::dioxus::core::Scoped {
+                scope: #cx_ident.scope,
+                props: #binding,
+            });
+        },
+    );
+
+    Ok(item_fn)
+}
+
+/// Creates a `render_to_string` function on the marker struct of a propless component.
+///
+/// The component is rebuilt in a fresh [`VirtualDom`](dioxus_core::VirtualDom) on every call, which is fine
+/// for smoke tests and static snippets, but real SSR should reuse a renderer.
+#[cfg(feature = "ssr")]
+fn get_render_to_string(orig_comp_fn: &ItemFn) -> Option<ItemImpl> {
+    let ItemFn { vis, sig, .. } = orig_comp_fn;
+
+    if !sig.generics.params.is_empty() {
+        return None;
+    }
+
+    let ident = &sig.ident;
+    let marker_ident = get_marker_ident(ident);
+
+    Some(parse_quote! {
+        impl #marker_ident {
+            /// Render this component to an HTML string with a temporary [`VirtualDom`](::dioxus::core::VirtualDom).
+            #vis fn render_to_string() -> ::std::string::String {
+                let mut dom = ::dioxus::core::VirtualDom::new(#ident);
+                let _ = dom.rebuild();
+                ::dioxus::ssr::render(&dom)
+            }
+        }
+    })
+}
+
+#[cfg(not(feature = "ssr"))]
+fn get_render_to_string(_orig_comp_fn: &ItemFn) -> Option<ItemImpl> {
+    None
+}
+
+/// Creates a `preview` function on the marker struct, which renders the component with placeholder props,
+/// and registers it as a [`ComponentPreview`](dioxus_core::ComponentPreview).
+///
+/// Props that aren't required are left out, and required props get the same placeholders as the generated
+/// `# Examples` docs. A required prop without an obvious placeholder is an error, since `todo!()` would panic.
+fn get_preview(orig_comp_fn: &ItemFn) -> Result<(ItemImpl, ItemMacro)> {
+    let ItemFn { vis, sig, .. } = orig_comp_fn;
+
+    if !sig.generics.params.is_empty() {
+        return Err(Error::new(
+            sig.generics.span(),
+            "Generic components can't have a preview",
+        ));
+    }
+
+    let mut props = Vec::new();
+
+    for arg in sig.inputs.iter().skip(1) {
+        let pt = match arg {
+            FnArg::Receiver(_) => unreachable!(), // ComponentBody prohibits receiver parameters.
+            FnArg::Typed(pt) => pt,
+        };
+
+        if !is_prop_required(pt) {
+            continue;
+        }
+
+        let placeholder = get_placeholder_value(&pt.ty);
+
+        if placeholder == "todo!()" {
+            return Err(Error::new(
+                pt.ty.span(),
+                "There's no placeholder for this prop in the preview. Give it a default with #[props(default)]",
+            ));
+        }
+
+        let ident = get_prop_field_ident(pt);
+        let placeholder: Expr = parse_str(placeholder)?;
+        props.push(quote!(#ident: #placeholder));
+    }
+
+    let ident = &sig.ident;
+    let marker_ident = get_marker_ident(ident);
+    let name = ident.to_string();
+
+    let preview_impl = parse_quote! {
+        impl #marker_ident {
+            /// Render this component with placeholder props.
+            #vis fn preview(cx: ::dioxus::core::Scope) -> ::dioxus::core::Element {
+                ::dioxus::prelude::render! { #ident { #(#props,)* } }
+            }
+        }
+    };
+
+    let registration = parse_quote! {
+        ::dioxus::core::exports::inventory::submit! {
+            ::dioxus::core::ComponentPreview {
+                name: #name,
+                render: #marker_ident::preview,
+            }
+        }
+    };
+
+    Ok((preview_impl, registration))
+}
+
+/// Creates an inherent impl on the props struct with constants describing the props.
+fn get_props_reflection_impl(orig_comp_fn: &ItemFn, props_struct: &ItemStruct) -> ItemImpl {
+    let struct_ident = &props_struct.ident;
+    let (impl_generics, ty_generics, where_clause) = props_struct.generics.split_for_impl();
+    let vis = &orig_comp_fn.vis;
+
+    // Counted from the same list as `DynProps::field_names`, so props disabled with `#[cfg]` are left out.
+    let field_names = get_field_name_exprs(props_struct);
+    let schema_parts = get_schema_parts(orig_comp_fn);
+
+    parse_quote! {
+        impl #impl_generics #struct_ident #ty_generics #where_clause {
+            /// The number of props this component takes.
+            #vis const PROPS_FIELD_COUNT: usize = <[&str]>::len(&[#(#field_names),*]);
+
+            /// A hash of the names and types of the props, which changes whenever a prop is added, removed,
+            /// renamed or retyped. Tools that cache component schemas can use it to invalidate their caches.
+            #vis fn schema_version() -> u32 {
+                // 32-bit FNV-1a over every `name: type;` in order.
+                const SCHEMA: &[&str] = &[#(#schema_parts),*];
+                let mut hash: u32 = 0x811c_9dc5;
+                let mut part = 0;
+                while part < SCHEMA.len() {
+                    let bytes = SCHEMA[part].as_bytes();
+                    let mut byte = 0;
+                    while byte < bytes.len() {
+                        hash = (hash ^ bytes[byte] as u32).wrapping_mul(0x0100_0193);
+                        byte += 1;
+                    }
+                    part += 1;
+                }
+                hash
+            }
+        }
+    }
+}
+
+/// Returns the names of the props as string literals, each with the `#[cfg]` attributes of its field,
+/// so they can be listed in an array that only holds the props that are compiled in.
+/// The PhantomData field for marker generics isn't a prop, so it's left out.
+fn get_field_name_exprs(props_struct: &ItemStruct) -> Vec<TokenStream2> {
+    props_struct
+        .fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref().map(|ident| (field, ident)))
+        .filter(|(_, ident)| *ident != "__phantom")
+        .map(|(field, ident)| {
+            let cfg_attrs = get_cfg_attrs(&field.attrs);
+            let name = ident.unraw().to_string();
+            quote!(#(#cfg_attrs)* #name)
+        })
+        .collect()
+}
+
+/// Implements `DynProps` for the props struct, listing the names of the props, so tools can read them without
+/// knowing the type.
+fn get_dyn_props_impl(props_struct: &ItemStruct) -> ItemImpl {
+    let field_names = get_field_name_exprs(props_struct);
+
+    let struct_ident = &props_struct.ident;
+    let (impl_generics, ty_generics, where_clause) = props_struct.generics.split_for_impl();
+
+    parse_quote! {
+        impl #impl_generics ::dioxus::core::DynProps for #struct_ident #ty_generics #where_clause {
+            fn field_names(&self) -> &'static [&'static str] {
+                &[#(#field_names),*]
+            }
+        }
+    }
+}
+
+/// Implements [`Default`] for the props struct by building it without setting anything.
+/// A required prop is an error, since there's nothing to build it with.
+///
+/// The builder's `build` requires `Default` for props whose type uses a type parameter and that fall back to
+/// `Default::default()`, so the impl gets the same bounds.
+fn get_default_impl(orig_comp_fn: &ItemFn, props_struct: &ItemStruct) -> Result<ItemImpl> {
+    let props = orig_comp_fn
+        .sig
+        .inputs
+        .iter()
+        .skip(1)
+        .map(|f| match f {
+            FnArg::Receiver(_) => unreachable!(), // ComponentBody prohibits receiver parameters.
+            FnArg::Typed(pt) => pt,
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(pt) = props.iter().find(|pt| is_prop_required(pt)) {
+        return Err(Error::new(
+            pt.span(),
+            "`default` needs every prop to be optional. \
+Give this prop a default with #[props(default)], or make it an `Option`",
+        ));
+    }
+
+    let struct_ident = &props_struct.ident;
+    let mut generics = props_struct.generics.clone();
+    let type_params = props_struct
+        .generics
+        .type_params()
+        .map(|tp| &tp.ident)
+        .collect::<Vec<_>>();
+
+    for pt in props {
+        let ty = &pt.ty;
+        let uses_type_param = type_params
+            .iter()
+            .any(|ident| tokens_contain_ident(ty.to_token_stream(), ident));
+
+        if uses_type_param && !has_default_value(pt) {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(#ty: ::core::default::Default));
+        }
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(parse_quote! {
+        impl #impl_generics ::core::default::Default for #struct_ident #ty_generics #where_clause {
+            fn default() -> Self {
+                Self::builder().build()
+            }
+        }
+    })
+}
+
+/// Checks if a prop's default is given as a value, like `#[props(default = 1)]` or `#[props(default_code = "1")]`,
+/// instead of being `Default::default()`.
+fn has_default_value(pt: &PatType) -> bool {
+    pt.attrs
+        .iter()
+        .filter_map(get_props_args)
+        .flatten()
+        .any(|arg| match arg {
+            Expr::Assign(assign) => matches!(
+                &*assign.left,
+                Expr::Path(path) if path.path.is_ident("default") || path.path.is_ident("default_code")
+            ),
+            _ => false,
+        })
+}
+
+/// Creates a `const fn new_const` on the props struct, taking every prop in order,
+/// so the props can be defined in a `const` or `static`.
+///
+/// Every prop has to be a `bool`, `char`, integer, float or `&str`, since other types, including `String`,
+/// might not be constructible in a const context. Generic props structs aren't supported either.
+/// It's an error otherwise, so the constructor never silently goes away when a prop is added.
+fn get_const_new_impl(orig_comp_fn: &ItemFn, props_struct: &ItemStruct) -> Result<ItemImpl> {
+    if let Some(type_param) = props_struct.generics.type_params().next() {
+        return Err(Error::new(
+            type_param.span(),
+            "`const_new` doesn't support generic components",
+        ));
+    }
+
+    let mut params = Vec::new();
+    let mut idents = Vec::new();
+
+    for arg in orig_comp_fn.sig.inputs.iter().skip(1) {
+        let FnArg::Typed(pt) = arg else {
+            unreachable!() // ComponentBody prohibits receiver parameters.
+        };
+
+        if !is_const_constructible_type(&pt.ty) {
+            return Err(Error::new(
+                pt.ty.span(),
+                "`const_new` needs every prop to be a `bool`, `char`, integer, float or `&str`",
+            ));
+        }
+
+        let cfg_attrs = get_cfg_attrs(&pt.attrs);
+        let ident = get_prop_field_ident(pt);
+        let ty = &pt.ty;
+        params.push(quote!(#(#cfg_attrs)* #ident: #ty));
+        idents.push(quote!(#(#cfg_attrs)* #ident));
+    }
+
+    let struct_ident = &props_struct.ident;
+    let (impl_generics, ty_generics, where_clause) = props_struct.generics.split_for_impl();
+    let vis = &orig_comp_fn.vis;
+
+    Ok(parse_quote! {
+        impl #impl_generics #struct_ident #ty_generics #where_clause {
+            /// Creates the props in a const context, like a `const` or `static` item. Every prop has to be passed,
+            /// defaults included.
+            #vis const fn new_const(#(#params),*) -> Self {
+                Self { #(#idents),* }
+            }
+        }
+    })
+}
+
+/// Creates a hidden `fn __new` on the props struct, taking every prop in order, so tests in the same crate can build
+/// the props without going through the builder. It's only compiled in with `cfg(test)`, so it doesn't add to the
+/// API of the props, or clash with their methods, outside of tests.
+///
+/// Unlike [`get_const_new_impl`], it works with props of any type. The PhantomData field for marker generics
+/// isn't a prop, so it's filled in instead of being a parameter.
+fn get_positional_new_impl(props_struct: &ItemStruct) -> ItemImpl {
+    let mut params = Vec::new();
+    let mut fields = Vec::new();
+
+    for field in props_struct.fields.iter() {
+        let Some(ident) = &field.ident else {
+            unreachable!() // The props struct always has named fields.
+        };
+
+        if ident == "__phantom" {
+            fields.push(quote!(#ident: ::core::marker::PhantomData));
+            continue;
+        }
+
+        let cfg_attrs = get_cfg_attrs(&field.attrs);
+        let ty = &field.ty;
+        params.push(quote!(#(#cfg_attrs)* #ident: #ty));
+        fields.push(quote!(#(#cfg_attrs)* #ident));
+    }
+
+    let struct_ident = &props_struct.ident;
+    let (impl_generics, ty_generics, where_clause) = props_struct.generics.split_for_impl();
+
+    parse_quote! {
+        impl #impl_generics #struct_ident #ty_generics #where_clause {
+            /// Creates the props from every prop in order, defaults included, without the builder.
+            /// Meant for tests, so validators and `into` conversions are skipped.
+            #[cfg(test)]
+            #[doc(hidden)]
+            #[allow(dead_code, clippy::too_many_arguments)]
+            pub(crate) fn __new(#(#params),*) -> Self {
+                Self { #(#fields),* }
+            }
+        }
+    }
+}
+
+/// Checks if the type is a scalar other than `String`, which can't be created in a const context.
+fn is_const_constructible_type(ty: &Type) -> bool {
+    if let Type::Path(type_path) = ty {
+        if let Some(segment) = type_path.path.segments.last() {
+            if segment.ident == "String" {
+                return false;
+            }
+        }
+    }
+
+    crate::utils::is_scalar_type(ty)
+}
+
+/// Returns the `name: type;` string of every prop, in order, each with the `#[cfg]` attributes of its argument,
+/// so the schema version only hashes the props that are compiled in.
+///
+/// The types are hashed as written, so the version is stable across compilers and builds,
+/// but changing how a type is spelled (e.g. `String` to `std::string::String`) changes it too.
+fn get_schema_parts(orig_comp_fn: &ItemFn) -> Vec<TokenStream2> {
+    orig_comp_fn
+        .sig
+        .inputs
+        .iter()
+        .skip(1)
+        .map(|f| match f {
+            FnArg::Receiver(_) => unreachable!(), // ComponentBody prohibits receiver parameters.
+            FnArg::Typed(pt) => {
+                let cfg_attrs = get_cfg_attrs(&pt.attrs);
+                let part = format!("{}: {};", get_prop_field_ident(pt), pt.ty.to_token_stream());
+                quote!(#(#cfg_attrs)* #part)
+            }
+        })
+        .collect()
+}
+
+/// Implements [`Display`](std::fmt::Display) for the props struct, printing the component name and every scalar field.
+fn get_display_impl(fn_ident: &Ident, props_struct: &ItemStruct) -> ItemImpl {
+    let struct_ident = &props_struct.ident;
+    let (impl_generics, ty_generics, where_clause) = props_struct.generics.split_for_impl();
+
+    let scalar_fields: Vec<&Ident> = props_struct
+        .fields
+        .iter()
+        .filter(|field| crate::utils::is_scalar_type(&field.ty))
+        // The format string can't leave out fields that are disabled with `#[cfg]`, so they aren't printed.
+        .filter(|field| !field.attrs.iter().any(|attr| attr.path().is_ident("cfg")))
+        .filter_map(|field| field.ident.as_ref())
+        .collect();
+
+    let format_string = if scalar_fields.is_empty() {
+        fn_ident.to_string()
+    } else {
+        let fields = scalar_fields
+            .iter()
+            .map(|field| format!("{}: {{:?}}", field.unraw()))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!("{fn_ident} {{{{ {fields} }}}}")
+    };
+
+    parse_quote! {
+        impl #impl_generics ::std::fmt::Display for #struct_ident #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                ::core::write!(f, #format_string #(, self.#scalar_fields)*)
+            }
+        }
+    }
+}
+
 /// The args and deserializing implementation for the [`crate::component`] macro.
 #[derive(Clone)]
 pub struct ComponentDeserializerArgs {
     pub case_check: bool,
+    pub strict_naming: bool,
+    pub display: bool,
+    pub preview: bool,
+    pub no_memo: bool,
+    pub non_exhaustive: bool,
+    pub copy: bool,
+    pub marker: bool,
+    pub reflect: bool,
+    pub const_new: bool,
+    pub default: bool,
+    pub doc: Option<LitStr>,
+    pub props: Option<Ident>,
+}
+
+impl Default for ComponentDeserializerArgs {
+    fn default() -> Self {
+        Self {
+            case_check: true,
+            strict_naming: false,
+            display: false,
+            preview: false,
+            no_memo: false,
+            non_exhaustive: false,
+            copy: false,
+            marker: false,
+            reflect: false,
+            const_new: false,
+            default: false,
+            doc: None,
+            props: None,
+        }
+    }
+}
+
+impl Parse for ComponentDeserializerArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut args = Self::default();
+
+        for meta in Punctuated::<Meta, Token![,]>::parse_terminated(input)? {
+            if meta.path().is_ident(crate::COMPONENT_ARG_CASE_CHECK_OFF) {
+                args.case_check = false;
+            } else if meta.path().is_ident(crate::COMPONENT_ARG_STRICT_NAMING) {
+                args.strict_naming = true;
+            } else if meta.path().is_ident(crate::COMPONENT_ARG_DISPLAY) {
+                args.display = true;
+            } else if meta.path().is_ident(crate::COMPONENT_ARG_PREVIEW) {
+                if cfg!(not(feature = "preview")) {
+                    return Err(Error::new(
+                        meta.span(),
+                        "The `preview` argument requires the `preview` feature of `dioxus`",
+                    ));
+                }
+
+                args.preview = true;
+            } else if meta.path().is_ident(crate::COMPONENT_ARG_NO_MEMO) {
+                args.no_memo = true;
+            } else if meta.path().is_ident(crate::COMPONENT_ARG_NON_EXHAUSTIVE) {
+                args.non_exhaustive = true;
+            } else if meta.path().is_ident(crate::COMPONENT_ARG_COPY) {
+                args.copy = true;
+            } else if meta.path().is_ident(crate::COMPONENT_ARG_MARKER) {
+                args.marker = true;
+            } else if meta.path().is_ident(crate::COMPONENT_ARG_REFLECT) {
+                args.reflect = true;
+            } else if meta.path().is_ident(crate::COMPONENT_ARG_CONST_NEW) {
+                args.const_new = true;
+            } else if meta.path().is_ident(crate::COMPONENT_ARG_DEFAULT) {
+                args.default = true;
+            } else if meta.path().is_ident(crate::COMPONENT_ARG_DOC) {
+                let doc_path_err = Error::new(
+                    meta.span(),
+                    "Expected a path to a markdown file, like `doc = \"docs/my_component.md\"`",
+                );
+                let Meta::NameValue(name_value) = &meta else {
+                    return Err(doc_path_err);
+                };
+                let Expr::Lit(ExprLit {
+                    lit: Lit::Str(path),
+                    ..
+                }) = &name_value.value
+                else {
+                    return Err(doc_path_err);
+                };
+
+                args.doc = Some(path.clone());
+            } else if meta.path().is_ident(crate::COMPONENT_ARG_PROPS) {
+                let props_name_err = Error::new(
+                    meta.span(),
+                    "Expected a name for the props struct, like `props = \"MyComponentProps\"`",
+                );
+                let Meta::NameValue(name_value) = &meta else {
+                    return Err(props_name_err);
+                };
+                let Expr::Lit(ExprLit {
+                    lit: Lit::Str(name),
+                    ..
+                }) = &name_value.value
+                else {
+                    return Err(props_name_err);
+                };
+
+                args.props = Some(name.parse::<Ident>().map_err(|_| props_name_err)?);
+            } else {
+                return Err(Error::new(
+                    meta.span(),
+                    format!("Unknown argument <{}>", meta.path().to_token_stream()),
+                ));
+            }
+        }
+
+        Ok(args)
+    }
 }
 
 /// The output fields and [`ToTokens`] implementation for the [`crate::component`] macro.
@@ -52,17 +980,62 @@ pub struct ComponentDeserializerArgs {
 pub struct ComponentDeserializerOutput {
     pub comp_fn: ItemFn,
     pub props_struct: Option<ItemStruct>,
+    pub partial_eq_impl: Option<ItemImpl>,
+    pub marker: Option<(ItemStruct, ItemImpl, Option<ItemType>)>,
+    pub render_to_string: Option<ItemImpl>,
+    pub display_impl: Option<ItemImpl>,
+    pub reflection_impl: Option<ItemImpl>,
+    pub dyn_props_impl: Option<ItemImpl>,
+    pub default_impl: Option<ItemImpl>,
+    pub const_new_impl: Option<ItemImpl>,
+    pub positional_new_impl: Option<ItemImpl>,
+    pub preview: Option<(ItemImpl, ItemMacro)>,
 }
 
 impl ToTokens for ComponentDeserializerOutput {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let comp_fn = &self.comp_fn;
         let props_struct = &self.props_struct;
+        let partial_eq_impl = &self.partial_eq_impl;
+        let (marker_struct, marker_impl, marker_alias) = match &self.marker {
+            Some((marker_struct, marker_impl, marker_alias)) => (
+                Some(marker_struct),
+                Some(marker_impl),
+                marker_alias.as_ref(),
+            ),
+            None => (None, None, None),
+        };
+        let render_to_string = &self.render_to_string;
+        let display_impl = &self.display_impl;
+        let reflection_impl = &self.reflection_impl;
+        let dyn_props_impl = &self.dyn_props_impl;
+        let default_impl = &self.default_impl;
+        let const_new_impl = &self.const_new_impl;
+        let positional_new_impl = &self.positional_new_impl;
+        let (preview_impl, preview_registration) = match &self.preview {
+            Some((preview_impl, preview_registration)) => {
+                (Some(preview_impl), Some(preview_registration))
+            }
+            None => (None, None),
+        };
 
         tokens.append_all(quote! {
             #props_struct
+            #partial_eq_impl
             #[allow(non_snake_case)]
             #comp_fn
+            #marker_struct
+            #marker_impl
+            #marker_alias
+            #render_to_string
+            #display_impl
+            #reflection_impl
+            #dyn_props_impl
+            #default_impl
+            #const_new_impl
+            #positional_new_impl
+            #preview_impl
+            #preview_registration
         });
     }
 }
@@ -71,20 +1044,58 @@ impl DeserializerArgs<ComponentDeserializerOutput> for ComponentDeserializerArgs
     fn to_output(&self, component_body: &ComponentBody) -> Result<ComponentDeserializerOutput> {
         let Signature { ident, .. } = &component_body.item_fn.sig;
 
-        if self.case_check && !is_pascal_case(&ident.to_string()) {
+        // Strict naming replaces the case check, which rejects acronyms like `HTMLParser`.
+        if self.strict_naming {
+            let name = ident.unraw().to_string();
+            if !is_upper_camel_case(&name) {
+                return Err(Error::new(
+                    ident.span(),
+                    format!(
+                        "Component names must be UpperCamelCase, like `{}`",
+                        to_upper_camel_case(&name)
+                    ),
+                ));
+            }
+        } else if self.case_check && !is_pascal_case(&ident.to_string()) {
             return Err(Error::new(ident.span(), COMPONENT_ARG_CASE_CHECK_ERROR));
         }
 
-        if component_body.has_extra_args {
-            Self::deserialize_with_props(component_body)
+        let component_body = &inject_context_args(component_body)?;
+        let component_body = &inject_key_arg(component_body)?;
+        let component_body = &wrap_async_body(component_body)?;
+        let component_body = &flatten_option_return(component_body)?;
+
+        let mut output = if component_body.has_extra_args {
+            self.deserialize_with_props(component_body)?
         } else {
-            Ok(Self::deserialize_no_props(component_body))
+            self.deserialize_no_props(component_body)
+        };
+
+        if self.preview {
+            output.preview = Some(get_preview(&component_body.item_fn)?);
+        }
+
+        // `include_str!` resolves the path relative to the file it's called from, which is the component's file,
+        // since the path literal keeps the span of the macro argument.
+        if let Some(doc_path) = &self.doc {
+            output
+                .comp_fn
+                .attrs
+                .insert(0, parse_quote!(#[doc = ::core::include_str!(#doc_path)]));
         }
+
+        Ok(output)
     }
 }
 
 impl ComponentDeserializerArgs {
-    fn deserialize_no_props(component_body: &ComponentBody) -> ComponentDeserializerOutput {
+    /// Whether the component gets a marker struct. The preview is a function on the marker, so it needs one too,
+    /// but only the `marker` argument exposes it under the component's name.
+    fn has_marker(&self) -> bool {
+        self.marker || self.preview
+    }
+
+    fn deserialize_no_props(&self, component_body: &ComponentBody) -> ComponentDeserializerOutput {
         let ComponentBody {
             item_fn,
             cx_pat_type,
@@ -93,14 +1104,35 @@ impl ComponentDeserializerArgs {
         let cx_pat = &cx_pat_type.pat;
 
         let comp_fn = get_out_comp_fn(item_fn, cx_pat);
+        // A hand-written props struct in the `Scope` type, like `cx: Scope<LabelProps>`, works the same as without
+        // the macro. There's nothing to render it with in `render_to_string`, though.
+        let (marker, render_to_string) = match get_explicit_props_type(cx_pat_type) {
+            _ if !self.has_marker() => (None, None),
+            Some(props_ty) => (get_marker(item_fn, props_ty.clone(), self.marker), None),
+            None => (
+                get_marker(item_fn, parse_quote!(()), self.marker),
+                get_render_to_string(item_fn),
+            ),
+        };
 
         ComponentDeserializerOutput {
             comp_fn,
             props_struct: None,
+            partial_eq_impl: None,
+            marker,
+            render_to_string,
+            display_impl: None,
+            reflection_impl: None,
+            dyn_props_impl: None,
+            default_impl: None,
+            const_new_impl: None,
+            positional_new_impl: None,
+            preview: None,
         }
     }
 
     fn deserialize_with_props(
+        &self,
         component_body: &ComponentBody,
     ) -> Result<ComponentDeserializerOutput> {
         let ComponentBody {
@@ -110,7 +1142,12 @@ impl ComponentDeserializerArgs {
         } = component_body;
         let cx_pat = &cx_pat_type.pat;
 
-        let comp_parsed = match parse2::<ComponentBody>(quote!(#item_fn)) {
+        let explicit_props_fn = get_explicit_props_type(cx_pat_type)
+            .map(|props_ty| move_explicit_props_into_args(item_fn, cx_pat_type, props_ty))
+            .transpose()?;
+        let props_item_fn = explicit_props_fn.as_ref().unwrap_or(item_fn);
+
+        let comp_parsed = match parse2::<ComponentBody>(quote!(#props_item_fn)) {
             Ok(comp_body) => comp_body,
             Err(e) => {
                 return Err(Error::new(
@@ -123,15 +1160,69 @@ impl ComponentDeserializerArgs {
             }
         };
 
-        let inlined_props_output = comp_parsed.deserialize(InlinePropsDeserializerArgs {})?;
-        let props_struct = inlined_props_output.props_struct;
+        let inlined_props_output = if self.no_memo {
+            comp_parsed.deserialize(NoMemoDeserializerArgs {
+                props_ident: self.props.clone(),
+            })?
+        } else {
+            comp_parsed.deserialize(InlinePropsDeserializerArgs {
+                props_ident: self.props.clone(),
+            })?
+        };
+        let mut props_struct = inlined_props_output.props_struct;
         let props_fn = inlined_props_output.comp_fn;
+        let partial_eq_impl = inlined_props_output.partial_eq_impl;
+
+        // Other crates can't use a struct expression then, so adding a prop later isn't a breaking change.
+        if self.non_exhaustive {
+            props_struct.attrs.push(parse_quote!(#[non_exhaustive]));
+        }
+
+        // Whether the props really are `Copy` is left to the derive, which errors on the first field that isn't.
+        if self.copy {
+            props_struct
+                .attrs
+                .push(parse_quote!(#[derive(::core::clone::Clone, ::core::marker::Copy)]));
+        }
 
         let comp_fn = get_out_comp_fn(&props_fn, cx_pat);
+        let props_ident = &props_struct.ident;
+        let marker = self
+            .has_marker()
+            .then(|| get_marker(item_fn, parse_quote!(#props_ident), self.marker))
+            .flatten();
+        let display_impl = self
+            .display
+            .then(|| get_display_impl(&item_fn.sig.ident, &props_struct));
+        let reflection_impl = self
+            .reflect
+            .then(|| get_props_reflection_impl(item_fn, &props_struct));
+        let dyn_props_impl = self.reflect.then(|| get_dyn_props_impl(&props_struct));
+        let default_impl = if self.default {
+            Some(get_default_impl(item_fn, &props_struct)?)
+        } else {
+            None
+        };
+        let const_new_impl = if self.const_new {
+            Some(get_const_new_impl(item_fn, &props_struct)?)
+        } else {
+            None
+        };
+        let positional_new_impl = get_positional_new_impl(&props_struct);
 
         Ok(ComponentDeserializerOutput {
             comp_fn,
             props_struct: Some(props_struct),
+            partial_eq_impl,
+            marker,
+            render_to_string: None,
+            display_impl,
+            reflection_impl,
+            dyn_props_impl,
+            default_impl,
+            const_new_impl,
+            positional_new_impl: Some(positional_new_impl),
+            preview: None,
         })
     }
 }
@@ -151,3 +1242,24 @@ fn is_pascal_case(input: &str) -> bool {
 
     true
 }
+
+/// Checks if the name starts with an uppercase letter and has no underscores. Runs of uppercase letters are allowed,
+/// so acronyms like `HTMLParser` pass.
+fn is_upper_camel_case(input: &str) -> bool {
+    input.starts_with(|c: char| c.is_ascii_uppercase()) && !input.contains('_')
+}
+
+/// Turns a name like `my_button` into `MyButton`, for the strict naming error.
+fn to_upper_camel_case(input: &str) -> String {
+    input
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
@@ -1,49 +1,69 @@
 use crate::component_body::{ComponentBody, DeserializerArgs};
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{quote, ToTokens, TokenStreamExt};
+use syn::ext::IdentExt;
+use syn::spanned::Spanned;
 use syn::token::Comma;
 use syn::{punctuated::Punctuated, *};
 
 /// The args and deserializing implementation for the [`crate::inline_props`] macro.
-#[derive(Clone)]
-pub struct InlinePropsDeserializerArgs;
+#[derive(Clone, Default)]
+pub struct InlinePropsDeserializerArgs {
+    /// The name of the generated props struct. Defaults to the component name followed by `Props`.
+    pub props_ident: Option<Ident>,
+}
+
+impl InlinePropsDeserializerArgs {
+    fn get_props_ident(&self, fn_ident: &Ident) -> Ident {
+        self.props_ident
+            .clone()
+            .unwrap_or_else(|| Ident::new(&format!("{fn_ident}Props"), fn_ident.span()))
+    }
+}
 
 /// The output fields and [`ToTokens`] implementation for the [`crate::inline_props`] macro.
 #[derive(Clone)]
 pub struct InlinePropsDeserializerOutput {
     pub comp_fn: ItemFn,
     pub props_struct: ItemStruct,
+    pub partial_eq_impl: Option<ItemImpl>,
 }
 
 impl ToTokens for InlinePropsDeserializerOutput {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let function = &self.comp_fn;
         let props_struct = &self.props_struct;
+        let partial_eq_impl = &self.partial_eq_impl;
 
         tokens.append_all(quote! {
             #function
             #props_struct
+            #partial_eq_impl
         });
     }
 }
 
 impl DeserializerArgs<InlinePropsDeserializerOutput> for InlinePropsDeserializerArgs {
     fn to_output(&self, component_body: &ComponentBody) -> Result<InlinePropsDeserializerOutput> {
+        check_prop_names(component_body)?;
+
+        let struct_ident = self.get_props_ident(&component_body.item_fn.sig.ident);
+        let props_struct = get_props_struct(component_body, &struct_ident);
+        let partial_eq_impl = get_partial_eq_impl(component_body, &props_struct);
+
         Ok(InlinePropsDeserializerOutput {
-            comp_fn: get_function(component_body),
-            props_struct: get_props_struct(component_body),
+            comp_fn: get_function(component_body, &struct_ident),
+            props_struct,
+            partial_eq_impl,
         })
     }
 }
 
-fn get_props_struct(component_body: &ComponentBody) -> ItemStruct {
+fn get_props_struct(component_body: &ComponentBody, struct_ident: &Ident) -> ItemStruct {
     let ComponentBody { item_fn, .. } = component_body;
     let ItemFn { vis, sig, .. } = item_fn;
     let Signature {
-        inputs,
-        ident: fn_ident,
-        generics,
-        ..
+        inputs, generics, ..
     } = sig;
 
     // Skip first arg since that's the context
@@ -51,21 +71,32 @@ fn get_props_struct(component_body: &ComponentBody) -> ItemStruct {
         match f {
             FnArg::Receiver(_) => unreachable!(), // Unreachable because of ComponentBody parsing
             FnArg::Typed(pt) => {
-                let arg_pat = &pt.pat; // Pattern (identifier)
+                let arg_ident = get_prop_field_ident(pt); // Identifier, without a `mut`, or the renamed one
                 let arg_colon = &pt.colon_token;
                 let arg_ty = &pt.ty; // Type
-                let arg_attrs = &pt.attrs; // Attributes
+                let arg_attrs = pt.attrs.iter().filter_map(strip_props_rename); // Attributes
 
                 quote! {
                     #(#arg_attrs)
                     *
-                    #vis #arg_pat #arg_colon #arg_ty
+                    #vis #arg_ident #arg_colon #arg_ty
                 }
             }
         }
     });
 
-    let struct_ident = Ident::new(&format!("{fn_ident}Props"), fn_ident.span());
+    // Generics that no field mentions would make the struct fail to compile with an "unused parameter" error,
+    // so they're carried by a defaulted PhantomData field instead.
+    let marker_generics = get_marker_generics(sig);
+    let phantom_field = if marker_generics.is_empty() {
+        None
+    } else {
+        Some(quote! {
+            #[props(default)]
+            __phantom: ::core::marker::PhantomData<(#(#marker_generics,)*)>
+        })
+    };
+    let struct_fields = struct_fields.chain(phantom_field);
 
     let first_lifetime = if let Some(GenericParam::Lifetime(lt)) = generics.params.first() {
         Some(lt)
@@ -73,7 +104,8 @@ fn get_props_struct(component_body: &ComponentBody) -> ItemStruct {
         None
     };
 
-    let struct_attrs = if first_lifetime.is_some() {
+    // Props with `#[props(skip_eq)]` get a hand-written PartialEq impl from `get_partial_eq_impl` instead.
+    let struct_attrs = if first_lifetime.is_some() || has_skip_eq_props(inputs.iter().skip(1)) {
         quote! { #[derive(Props)] }
     } else {
         quote! { #[derive(Props, PartialEq)] }
@@ -102,17 +134,137 @@ fn get_props_struct(component_body: &ComponentBody) -> ItemStruct {
         quote! { #generics }
     };
 
+    // `Generics` doesn't print its where clause, but the props derive reads the bounds from the struct, so the
+    // builder only enforces them if they're on the struct too.
+    let where_clause = &generics.where_clause;
+
     parse_quote! {
         #struct_attrs
         #[allow(non_camel_case_types)]
         #vis struct #struct_ident #struct_generics
+        #where_clause
         {
             #(#struct_fields),*
         }
     }
 }
 
-fn get_props_docs(fn_ident: &Ident, inputs: Vec<&FnArg>) -> Vec<Attribute> {
+/// Checks if any of the given arguments has a `#[props(skip_eq)]` attribute.
+fn has_skip_eq_props<'a>(mut inputs: impl Iterator<Item = &'a FnArg>) -> bool {
+    inputs.any(|f| match f {
+        FnArg::Receiver(_) => unreachable!(), // ComponentBody prohibits receiver parameters.
+        FnArg::Typed(pt) => has_props_flag(&pt.attrs, "skip_eq"),
+    })
+}
+
+/// Creates a `PartialEq` impl for the props struct that ignores the props marked with `#[props(skip_eq)]`,
+/// so components that take non-comparable props (like closures) can still memoize on the rest.
+///
+/// Returns `None` if there are no such props, in which case `PartialEq` is derived as usual.
+fn get_partial_eq_impl(
+    component_body: &ComponentBody,
+    props_struct: &ItemStruct,
+) -> Option<ItemImpl> {
+    let inputs = &component_body.item_fn.sig.inputs;
+
+    // Structs with a lifetime never implement PartialEq, since they can't be memoized anyway.
+    if props_struct.generics.lifetimes().next().is_some()
+        || !has_skip_eq_props(inputs.iter().skip(1))
+    {
+        return None;
+    }
+
+    // Each comparison is its own statement, so the ones for `#[cfg]`-disabled props can be left out.
+    let comparisons = inputs
+        .iter()
+        .skip(1)
+        .filter_map(|f| match f {
+            FnArg::Receiver(_) => unreachable!(), // ComponentBody prohibits receiver parameters.
+            FnArg::Typed(pt) => (!has_props_flag(&pt.attrs, "skip_eq")).then_some(pt),
+        })
+        .map(|pt| {
+            let cfg_attrs = get_cfg_attrs(&pt.attrs);
+            let ident = get_prop_field_ident(pt);
+            quote! {
+                #(#cfg_attrs)*
+                if self.#ident != other.#ident {
+                    return false;
+                }
+            }
+        });
+    let eq_body = quote! {
+        #(#comparisons)*
+        true
+    };
+
+    let struct_ident = &props_struct.ident;
+    let mut generics = props_struct.generics.clone();
+    let type_params: Vec<Ident> = generics.type_params().map(|tp| tp.ident.clone()).collect();
+    for type_param in type_params {
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(#type_param: ::core::cmp::PartialEq));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Some(parse_quote! {
+        impl #impl_generics ::core::cmp::PartialEq for #struct_ident #ty_generics #where_clause {
+            fn eq(&self, other: &Self) -> bool {
+                #eq_body
+            }
+        }
+    })
+}
+
+/// Checks if there's a `#[props(...)]` attribute that contains the given flag, like `default` or `skip_eq`.
+fn has_props_flag(attrs: &[Attribute], flag: &str) -> bool {
+    attrs.iter().any(|attr| {
+        let Meta::List(list) = &attr.meta else {
+            return false;
+        };
+
+        list.path == parse_quote!(props)
+            && list
+                .tokens
+                .clone()
+                .into_iter()
+                .any(|token| matches!(token, proc_macro2::TokenTree::Ident(ident) if ident == flag))
+    })
+}
+
+/// Returns the type parameters of the component that aren't used by the type of any prop.
+fn get_marker_generics(sig: &Signature) -> Vec<&Ident> {
+    let field_types: Vec<TokenStream2> = sig
+        .inputs
+        .iter()
+        .skip(1)
+        .filter_map(|f| match f {
+            FnArg::Receiver(_) => None,
+            FnArg::Typed(pt) => Some(pt.ty.to_token_stream()),
+        })
+        .collect();
+
+    sig.generics
+        .type_params()
+        .map(|tp| &tp.ident)
+        .filter(|ident| {
+            !field_types
+                .iter()
+                .any(|ty| tokens_contain_ident(ty.clone(), ident))
+        })
+        .collect()
+}
+
+pub(crate) fn tokens_contain_ident(tokens: TokenStream2, ident: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(i) => &i == ident,
+        proc_macro2::TokenTree::Group(group) => tokens_contain_ident(group.stream(), ident),
+        _ => false,
+    })
+}
+
+fn get_props_docs(fn_ident: &Ident, struct_ident: &Ident, inputs: Vec<&FnArg>) -> Vec<Attribute> {
     if inputs.len() <= 1 {
         return Vec::new();
     }
@@ -153,7 +305,7 @@ fn get_props_docs(fn_ident: &Ident, inputs: Vec<&FnArg>) -> Vec<Attribute> {
                     });
 
                 Some((
-                    &pt.pat,
+                    get_prop_field_ident(pt),
                     &pt.ty,
                     pt.attrs.iter().find_map(|attr| {
                         if attr.path() != &parse_quote!(deprecated) {
@@ -174,7 +326,7 @@ fn get_props_docs(fn_ident: &Ident, inputs: Vec<&FnArg>) -> Vec<Attribute> {
         .collect::<Vec<_>>();
 
     let mut props_docs = Vec::with_capacity(5);
-    let props_def_link = fn_ident.to_string() + "Props";
+    let props_def_link = struct_ident.to_string();
     let header =
         format!("# Props\n*For details, see the [props struct definition]({props_def_link}).*");
 
@@ -221,10 +373,254 @@ fn get_props_docs(fn_ident: &Ident, inputs: Vec<&FnArg>) -> Vec<Attribute> {
         });
     }
 
+    props_docs.extend(get_example_docs(fn_ident, &inputs));
+
     props_docs
 }
 
-fn get_function(component_body: &ComponentBody) -> ItemFn {
+/// Creates an `# Examples` section that renders the component with every required prop set to a placeholder value.
+///
+/// The placeholders are only meant to show which props must be passed, so the example is fenced with `ignore`.
+fn get_example_docs(fn_ident: &Ident, inputs: &[&FnArg]) -> Vec<Attribute> {
+    let required_props = inputs
+        .iter()
+        .filter_map(|f| match f {
+            FnArg::Receiver(_) => unreachable!(), // ComponentBody prohibits receiver parameters.
+            FnArg::Typed(pt) => Some(pt),
+        })
+        .filter(|pt| is_prop_required(pt))
+        .map(|pt| {
+            let name = get_prop_field_ident(pt);
+            format!("        {name}: {},\n", get_placeholder_value(&pt.ty))
+        })
+        .collect::<String>();
+
+    let example =
+        format!("```rust, ignore\nrsx! {{\n    {fn_ident} {{\n{required_props}    }}\n}}\n```");
+
+    vec![
+        parse_quote! { #[doc = "# Examples"] },
+        parse_quote! { #[doc = #example] },
+    ]
+}
+
+/// Checks if a prop has to be set by the caller, i.e. it's not `children`, has no default,
+/// and isn't an `Option` (unless it's marked with `#[props(!optional)]`).
+pub(crate) fn is_prop_required(pt: &PatType) -> bool {
+    if get_prop_field_ident(pt) == "children" {
+        return false;
+    }
+
+    let mut has_default = false;
+    let mut ignore_option = false;
+
+    for arg in pt.attrs.iter().filter_map(get_props_args).flatten() {
+        match arg {
+            Expr::Path(path) if path.path.is_ident("default") || path.path.is_ident("optional") => {
+                has_default = true;
+            }
+            Expr::Assign(assign) => {
+                if let Expr::Path(path) = &*assign.left {
+                    if path.path.is_ident("default") || path.path.is_ident("default_code") {
+                        has_default = true;
+                    }
+                }
+            }
+            Expr::Unary(ExprUnary {
+                op: UnOp::Not(_),
+                expr,
+                ..
+            }) => {
+                if let Expr::Path(path) = &*expr {
+                    if path.path.is_ident("optional") {
+                        ignore_option = true;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if has_default {
+        return false;
+    }
+
+    if let Type::Path(type_path) = &*pt.ty {
+        if let Some(segment) = type_path.path.segments.last() {
+            if segment.ident == "Option" && !ignore_option {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+/// Returns the `#[cfg(...)]` attributes of a prop, which have to be repeated wherever its field is used.
+pub(crate) fn get_cfg_attrs(attrs: &[Attribute]) -> Vec<&Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cfg"))
+        .collect()
+}
+
+/// Returns the identifier a prop is bound to, without the `mut` it might be declared with.
+pub(crate) fn get_prop_ident(pt: &PatType) -> &Ident {
+    match &*pt.pat {
+        Pat::Ident(pat_ident) => &pat_ident.ident,
+        _ => unreachable!(), // ComponentBody only allows identifier patterns.
+    }
+}
+
+/// Returns the name of a prop's field and setter, which is the identifier the prop is bound to,
+/// unless it's renamed with `#[props(rename = "...")]`.
+pub(crate) fn get_prop_field_ident(pt: &PatType) -> Ident {
+    match get_props_rename(&pt.attrs) {
+        Some(Ok(ident)) => ident,
+        _ => get_prop_ident(pt).clone(),
+    }
+}
+
+/// Returns the identifier from a `#[props(rename = "...")]` attribute, or an error spanned to the string
+/// if it isn't a valid identifier.
+fn get_props_rename(attrs: &[Attribute]) -> Option<Result<Ident>> {
+    attrs.iter().find_map(|attr| {
+        let rename = get_props_args(attr)?.into_iter().find_map(|arg| match arg {
+            Expr::Assign(assign) if is_rename_assign(&assign) => Some(assign.right),
+            _ => None,
+        })?;
+
+        let Expr::Lit(ExprLit {
+            lit: Lit::Str(name),
+            ..
+        }) = &*rename
+        else {
+            return Some(Err(Error::new(
+                rename.span(),
+                "Expected a string, like `rename = \"kind\"`",
+            )));
+        };
+
+        Some(
+            parse_str::<Ident>(&name.value())
+                .map(|mut ident| {
+                    ident.set_span(name.span());
+                    ident
+                })
+                .map_err(|_| {
+                    Error::new(
+                        name.span(),
+                        "Not a valid identifier. Keywords have to be raw identifiers, like `r#type`",
+                    )
+                }),
+        )
+    })
+}
+
+/// Checks that every renamed prop has a valid name, that no two props end up with the same name, and that no prop is
+/// named `key`.
+///
+/// The props derive would fail on the duplicate field with an error that doesn't point at the component.
+/// `key` is the key of the component in keyed lists, which `rsx!` never passes to the props builder. `#[component]`
+/// takes a `key` argument out of the props before this, so only props renamed to `key` are left.
+fn check_prop_names(component_body: &ComponentBody) -> Result<()> {
+    let mut names = Vec::new();
+
+    for arg in component_body.item_fn.sig.inputs.iter().skip(1) {
+        let FnArg::Typed(pt) = arg else {
+            unreachable!() // ComponentBody prohibits receiver parameters.
+        };
+
+        let field_ident = match get_props_rename(&pt.attrs) {
+            Some(rename) => rename?,
+            None => get_prop_ident(pt).clone(),
+        };
+
+        if field_ident.unraw() == "key" {
+            return Err(Error::new(
+                field_ident.span(),
+                "`key` can't be a prop name, since it's the key of the component in keyed lists, \
+which `rsx!` doesn't pass to the props. Take the key as a `key: Option<&str>` argument instead",
+            ));
+        }
+
+        // The span is the rename string for renamed props, so the error points at the name that collides.
+        if names.contains(&field_ident.unraw()) {
+            return Err(Error::new(
+                field_ident.span(),
+                format!("duplicate prop name `{}`", field_ident.unraw()),
+            ));
+        }
+
+        names.push(field_ident.unraw());
+    }
+
+    Ok(())
+}
+
+/// Removes `rename = "..."` from a `#[props(...)]` attribute, since it's only for this macro and the
+/// props derive doesn't know it. Returns `None` if nothing else is left in the attribute.
+fn strip_props_rename(attr: &Attribute) -> Option<Attribute> {
+    let Some(args) = get_props_args(attr) else {
+        return Some(attr.clone());
+    };
+
+    let remaining: Vec<&Expr> = args
+        .iter()
+        .filter(|arg| !matches!(arg, Expr::Assign(assign) if is_rename_assign(assign)))
+        .collect();
+
+    if remaining.len() == args.len() {
+        Some(attr.clone())
+    } else if remaining.is_empty() {
+        None
+    } else {
+        Some(parse_quote!(#[props(#(#remaining),*)]))
+    }
+}
+
+/// Parses the arguments of a `#[props(...)]` attribute, or returns `None` if it's a different attribute.
+pub(crate) fn get_props_args(attr: &Attribute) -> Option<Punctuated<Expr, Comma>> {
+    if !attr.path().is_ident("props") {
+        return None;
+    }
+
+    attr.parse_args_with(Punctuated::<Expr, Comma>::parse_terminated)
+        .ok()
+}
+
+fn is_rename_assign(assign: &ExprAssign) -> bool {
+    matches!(&*assign.left, Expr::Path(path) if path.path.is_ident("rename"))
+}
+
+/// Returns a placeholder expression for the given type, or `todo!()` for types without an obvious one.
+pub(crate) fn get_placeholder_value(ty: &Type) -> &'static str {
+    match ty {
+        Type::Reference(reference) if reference.elem.to_token_stream().to_string() == "str" => {
+            "\"\""
+        }
+        Type::Path(type_path) => {
+            let Some(segment) = type_path.path.segments.last() else {
+                return "todo!()";
+            };
+
+            match segment.ident.to_string().as_str() {
+                "String" => "String::new()",
+                "bool" => "false",
+                "char" => "' '",
+                "f32" | "f64" => "0.0",
+                "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64"
+                | "u128" | "usize" => "0",
+                "Vec" => "Vec::new()",
+                "Option" => "None",
+                _ => "todo!()",
+            }
+        }
+        _ => "todo!()",
+    }
+}
+
+fn get_function(component_body: &ComponentBody, struct_ident: &Ident) -> ItemFn {
     let ComponentBody {
         item_fn,
         cx_pat_type,
@@ -247,13 +643,47 @@ fn get_function(component_body: &ComponentBody) -> ItemFn {
     let Generics { where_clause, .. } = generics;
 
     let cx_pat = &cx_pat_type.pat;
-    let struct_ident = Ident::new(&format!("{fn_ident}Props"), fn_ident.span());
 
     // Skip first arg since that's the context
-    let struct_field_names = inputs.iter().skip(1).filter_map(|f| match f {
-        FnArg::Receiver(_) => unreachable!(), // ComponentBody prohibits receiver parameters.
-        FnArg::Typed(pt) => Some(&pt.pat),
+    let props: Vec<&PatType> = inputs
+        .iter()
+        .skip(1)
+        .filter_map(|f| match f {
+            FnArg::Receiver(_) => unreachable!(), // ComponentBody prohibits receiver parameters.
+            FnArg::Typed(pt) => Some(pt),
+        })
+        .collect();
+    // Renamed props are bound to the original name, so the body doesn't change.
+    // The `#[cfg]`s of a prop are kept, since its field doesn't exist when they're disabled.
+    let struct_field_names = props.iter().map(|pt| {
+        let cfg_attrs = get_cfg_attrs(&pt.attrs);
+        let binding = get_prop_ident(pt);
+        let field_ident = get_prop_field_ident(pt);
+        if field_ident == *binding {
+            quote! { #(#cfg_attrs)* #binding }
+        } else {
+            quote! { #(#cfg_attrs)* #field_ident: #binding }
+        }
     });
+    // The props are borrowed from the scope, so the ones declared `mut` are rebound to a mutable local that still holds
+    // the reference, which can then point somewhere else. The others stay immutable, which keeps `unused_mut` quiet.
+    let mut_rebinds = props
+        .iter()
+        .filter(|pt| matches!(&*pt.pat, Pat::Ident(pat_ident) if pat_ident.mutability.is_some()))
+        .map(|pt| {
+            let cfg_attrs = get_cfg_attrs(&pt.attrs);
+            let ident = get_prop_ident(pt);
+            quote! {
+                #(#cfg_attrs)*
+                let mut #ident = #ident;
+            }
+        });
+    // The PhantomData field for marker generics isn't a real prop, so skip over it.
+    let rest_pattern = if get_marker_generics(sig).is_empty() {
+        None
+    } else {
+        Some(quote! { , .. })
+    };
 
     let first_lifetime = if let Some(GenericParam::Lifetime(lt)) = generics.params.first() {
         Some(lt)
@@ -261,12 +691,23 @@ fn get_function(component_body: &ComponentBody) -> ItemFn {
         None
     };
 
+    // Type parameter defaults, like `T = String`, are kept on the props struct, but they aren't allowed on functions.
+    let generics_no_defaults = {
+        let mut generics = generics.clone();
+        for tp in generics.type_params_mut() {
+            tp.eq_token = None;
+            tp.default = None;
+        }
+
+        generics
+    };
+
     let (scope_lifetime, fn_generics) = if let Some(lt) = first_lifetime {
-        (quote! { #lt, }, generics.clone())
+        (quote! { #lt, }, generics_no_defaults.clone())
     } else {
         let lifetime: LifetimeParam = parse_quote! { 'a };
 
-        let mut fn_generics = generics.clone();
+        let mut fn_generics = generics_no_defaults.clone();
         fn_generics
             .params
             .insert(0, GenericParam::Lifetime(lifetime.clone()));
@@ -275,7 +716,7 @@ fn get_function(component_body: &ComponentBody) -> ItemFn {
     };
 
     let generics_no_bounds = {
-        let mut generics = generics.clone();
+        let mut generics = generics_no_defaults;
         generics.params = generics
             .params
             .iter()
@@ -293,7 +734,7 @@ fn get_function(component_body: &ComponentBody) -> ItemFn {
         generics
     };
 
-    let props_docs = get_props_docs(fn_ident, inputs.iter().skip(1).collect());
+    let props_docs = get_props_docs(fn_ident, struct_ident, inputs.iter().skip(1).collect());
 
     parse_quote! {
         #(#fn_attrs)*
@@ -301,7 +742,10 @@ fn get_function(component_body: &ComponentBody) -> ItemFn {
         #asyncness #vis fn #fn_ident #fn_generics (#cx_pat: Scope<#scope_lifetime #struct_ident #generics_no_bounds>) #fn_output
         #where_clause
         {
-            let #struct_ident { #(#struct_field_names),* } = &#cx_pat.props;
+            // Not every prop has to be read by the body, so don't warn about the ones that aren't.
+            #[allow(unused_variables)]
+            let #struct_ident { #(#struct_field_names),* #rest_pattern } = &#cx_pat.props;
+            #(#mut_rebinds)*
             #fn_block
         }
     }
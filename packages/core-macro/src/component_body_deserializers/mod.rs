@@ -2,3 +2,5 @@
 
 pub mod component;
 pub mod inline_props;
+pub mod no_memo;
+pub mod signature;
@@ -0,0 +1,44 @@
+use crate::component_body::{ComponentBody, DeserializerArgs};
+use crate::component_body_deserializers::inline_props::{
+    InlinePropsDeserializerArgs, InlinePropsDeserializerOutput,
+};
+use syn::*;
+
+/// The args and deserializing implementation for components that never memoize, i.e. `#[component(no_memo)]`.
+///
+/// This builds on the [`InlinePropsDeserializerArgs`] output: the function is the same, but the props struct
+/// doesn't derive `PartialEq` and is marked with `#[props(no_memo)]`, so the component re-renders every
+/// time its parent does. The [`component`](crate::component) deserializer uses it in place of the
+/// inline props deserializer when the `no_memo` argument is passed.
+#[derive(Clone, Default)]
+pub struct NoMemoDeserializerArgs {
+    /// The name of the generated props struct, see [`InlinePropsDeserializerArgs::props_ident`].
+    pub props_ident: Option<Ident>,
+}
+
+impl DeserializerArgs<InlinePropsDeserializerOutput> for NoMemoDeserializerArgs {
+    fn to_output(&self, component_body: &ComponentBody) -> Result<InlinePropsDeserializerOutput> {
+        let InlinePropsDeserializerOutput {
+            comp_fn,
+            mut props_struct,
+            ..
+        } = component_body.deserialize(InlinePropsDeserializerArgs {
+            props_ident: self.props_ident.clone(),
+        })?;
+
+        // Swap `#[derive(Props, PartialEq)]` for a plain `Props` derive.
+        props_struct
+            .attrs
+            .retain(|attr| !attr.path().is_ident("derive"));
+        props_struct
+            .attrs
+            .insert(0, parse_quote!(#[props(no_memo)]));
+        props_struct.attrs.insert(0, parse_quote!(#[derive(Props)]));
+
+        Ok(InlinePropsDeserializerOutput {
+            comp_fn,
+            props_struct,
+            partial_eq_impl: None,
+        })
+    }
+}
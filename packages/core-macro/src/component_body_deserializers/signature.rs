@@ -0,0 +1,114 @@
+use crate::component_body::{ComponentBody, DeserializerArgs};
+use crate::component_body_deserializers::component::{
+    get_marker_ident, is_key_arg, ComponentDeserializerArgs, ComponentDeserializerOutput,
+    CONTEXT_ARG_ATTR,
+};
+use crate::component_body_deserializers::inline_props::get_prop_field_ident;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, ToTokens, TokenStreamExt};
+use syn::*;
+
+/// The args and deserializing implementation for the [`crate::component`] macro, with the component's signature.
+///
+/// This builds on the [`ComponentDeserializerArgs`] output, and adds a hidden `__COMPONENT_SIGNATURE` constant to
+/// the component's marker struct, like `"Greeting(name: String, count: u32)"`. It lists every prop with its type as
+/// written, so devtools can show a component's props without parsing its source. Every component gets a signature:
+/// components without a marker struct, like generic ones or ones without the `marker` argument, get a bare hidden
+/// struct under the marker's name to hold it.
+#[derive(Clone)]
+pub struct SignatureDeserializerArgs {
+    pub component_args: ComponentDeserializerArgs,
+}
+
+/// The output fields and [`ToTokens`] implementation for the [`SignatureDeserializerArgs`].
+#[derive(Clone)]
+pub struct SignatureDeserializerOutput {
+    pub component: ComponentDeserializerOutput,
+    /// The bare struct holding the signature, for components that don't have a marker struct.
+    pub signature_struct: Option<ItemStruct>,
+    pub signature: ItemImpl,
+}
+
+impl ToTokens for SignatureDeserializerOutput {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let component = &self.component;
+        let signature_struct = &self.signature_struct;
+        let signature = &self.signature;
+
+        tokens.append_all(quote! {
+            #component
+            #signature_struct
+            #signature
+        });
+    }
+}
+
+impl DeserializerArgs<SignatureDeserializerOutput> for SignatureDeserializerArgs {
+    fn to_output(&self, component_body: &ComponentBody) -> Result<SignatureDeserializerOutput> {
+        let component = component_body.deserialize(self.component_args.clone())?;
+        let signature_struct = component
+            .marker
+            .is_none()
+            .then(|| get_signature_struct(&component_body.item_fn));
+        let signature = get_signature_impl(&component_body.item_fn);
+
+        Ok(SignatureDeserializerOutput {
+            component,
+            signature_struct,
+            signature,
+        })
+    }
+}
+
+/// Creates a bare hidden struct under the marker's name, so components without a marker still have somewhere
+/// to put their signature. It isn't generic, even for generic components, since the signature doesn't need it.
+fn get_signature_struct(orig_comp_fn: &ItemFn) -> ItemStruct {
+    let ItemFn { vis, sig, .. } = orig_comp_fn;
+    let marker_ident = get_marker_ident(&sig.ident);
+
+    parse_quote! {
+        #[doc(hidden)]
+        #[allow(non_camel_case_types)]
+        #vis struct #marker_ident {}
+    }
+}
+
+/// Creates an inherent impl on the marker struct with the `__COMPONENT_SIGNATURE` constant.
+fn get_signature_impl(orig_comp_fn: &ItemFn) -> ItemImpl {
+    let ItemFn { vis, sig, .. } = orig_comp_fn;
+    let ident = &sig.ident;
+
+    // Context arguments and the key aren't props, so a caller can't pass them.
+    let props = sig
+        .inputs
+        .iter()
+        .skip(1)
+        .filter_map(|f| match f {
+            FnArg::Receiver(_) => unreachable!(), // ComponentBody prohibits receiver parameters.
+            FnArg::Typed(pt) => Some(pt),
+        })
+        .filter(|pt| {
+            !pt.attrs
+                .iter()
+                .any(|attr| attr.path().is_ident(CONTEXT_ARG_ATTR))
+                && !is_key_arg(pt)
+        })
+        .map(|pt| {
+            format!(
+                "{}: {}",
+                get_prop_field_ident(pt),
+                crate::utils::format_type_string(&pt.ty)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let signature = format!("{ident}({props})");
+    let marker_ident = get_marker_ident(ident);
+
+    parse_quote! {
+        impl #marker_ident {
+            #[doc(hidden)]
+            #vis const __COMPONENT_SIGNATURE: &str = #signature;
+        }
+    }
+}
@@ -5,9 +5,7 @@
 use proc_macro::TokenStream;
 use quote::ToTokens;
 use rsx::RenderCallBody;
-use syn::parse::Parser;
-use syn::punctuated::Punctuated;
-use syn::{parse_macro_input, Path, Token};
+use syn::parse_macro_input;
 
 mod component_body;
 mod component_body_deserializers;
@@ -15,9 +13,10 @@ mod props;
 mod utils;
 
 // mod rsx;
-use crate::component_body::ComponentBody;
+use crate::component_body::{has_untyped_arg, ComponentBody};
 use crate::component_body_deserializers::component::ComponentDeserializerArgs;
 use crate::component_body_deserializers::inline_props::InlinePropsDeserializerArgs;
+use crate::component_body_deserializers::signature::SignatureDeserializerArgs;
 use dioxus_rsx as rsx;
 
 #[proc_macro]
@@ -86,15 +85,31 @@ pub fn render(s: TokenStream) -> TokenStream {
 #[proc_macro_attribute]
 #[deprecated(note = "Use `#[component]` instead.")]
 pub fn inline_props(_args: TokenStream, s: TokenStream) -> TokenStream {
+    // rustc already reports an argument without a type, so expanding to nothing keeps that the only error.
+    if has_untyped_arg(s.clone().into()) {
+        return TokenStream::new();
+    }
     let comp_body = parse_macro_input!(s as ComponentBody);
 
-    match comp_body.deserialize(InlinePropsDeserializerArgs {}) {
+    match comp_body.deserialize(InlinePropsDeserializerArgs::default()) {
         Err(e) => e.to_compile_error().into(),
         Ok(output) => output.to_token_stream().into(),
     }
 }
 
 pub(crate) const COMPONENT_ARG_CASE_CHECK_OFF: &str = "no_case_check";
+pub(crate) const COMPONENT_ARG_STRICT_NAMING: &str = "strict_naming";
+pub(crate) const COMPONENT_ARG_DISPLAY: &str = "display";
+pub(crate) const COMPONENT_ARG_PREVIEW: &str = "preview";
+pub(crate) const COMPONENT_ARG_NO_MEMO: &str = "no_memo";
+pub(crate) const COMPONENT_ARG_NON_EXHAUSTIVE: &str = "non_exhaustive";
+pub(crate) const COMPONENT_ARG_COPY: &str = "copy";
+pub(crate) const COMPONENT_ARG_MARKER: &str = "marker";
+pub(crate) const COMPONENT_ARG_REFLECT: &str = "reflect";
+pub(crate) const COMPONENT_ARG_CONST_NEW: &str = "const_new";
+pub(crate) const COMPONENT_ARG_DEFAULT: &str = "default";
+pub(crate) const COMPONENT_ARG_DOC: &str = "doc";
+pub(crate) const COMPONENT_ARG_PROPS: &str = "props";
 
 /// Streamlines component creation.
 /// This is the recommended way of creating components,
@@ -105,6 +120,42 @@ pub(crate) const COMPONENT_ARG_CASE_CHECK_OFF: &str = "no_case_check";
 /// **This will be removed/deprecated in a future update in favor of a more complete Clippy-backed linting system.**
 /// The reasoning behind this is that Clippy allows more robust and powerful lints, whereas
 /// macros are extremely limited.
+/// * `strict_naming` - Checks the component name for `UpperCamelCase` instead of the default `PascalCase` check.
+/// The name has to start with an uppercase letter and can't contain underscores, but runs of uppercase letters
+/// are allowed, so acronyms like `HTMLParser` pass where the default check rejects them. The error suggests
+/// the `UpperCamelCase` spelling, like `MyButton` for `my_button`.
+/// * `display` - Implements [`Display`](std::fmt::Display) for the generated props struct.
+/// The output is the component name followed by the `Debug` representation of every scalar prop
+/// (strings, `char`s, `bool`s, integers and floats), e.g. `Greeting { name: "bob", count: 3 }`.
+/// Other props, like event handlers or children, are left out.
+/// * `preview` - Generates a `preview` function on the component's hidden marker struct, which renders the
+/// component with placeholder props (empty strings, zeros, `false`, ...), and registers it so it can be listed with
+/// `dioxus::core::previews()`. Only required props get a placeholder, so give other props a `#[props(default)]`.
+/// Requires the `preview` feature and doesn't support generic components.
+/// * `no_memo` - Makes the component re-render every time its parent does, instead of only when its props change.
+/// The generated props struct doesn't implement `PartialEq`, so props that can't be compared are allowed.
+/// * `non_exhaustive` - Marks the generated props struct with `#[non_exhaustive]`, so other crates can only create it
+/// through the props builder, e.g. with `rsx!`, and adding a prop isn't a breaking change.
+/// * `copy` - Derives `Clone` and `Copy` for the generated props struct, for components whose props are all `Copy`,
+/// like borrowed props. A prop that isn't `Copy` is a compile error.
+/// * `marker` - Exposes a zero-sized `Copy` marker struct under the same name as a non-generic component,
+/// which dereferences to the component function. This lets components be stored uniformly, e.g. `vec![*Home {}, *About {}]`.
+/// With the `ssr` feature, the marker also has a `render_to_string` function for propless components. It's opt-in, since the name clashes with any other type of the same name in the module.
+/// The struct itself is hidden as `__dioxus_component_marker_` followed by the component name.
+/// * `reflect` - Adds a `PROPS_FIELD_COUNT` constant to the generated props struct, holding the number of props,
+/// and a `schema_version()` function, returning a hash of the prop names and types that changes whenever they do.
+/// It also implements `DynProps` for the props struct, so tools can list the names of its props through
+/// `field_names()` without knowing its type. Props disabled with `#[cfg]` and `#[from_context]` arguments aren't counted.
+/// * `const_new` - Adds a `const fn new_const` to the generated props struct, taking every prop in order, defaults
+/// included. This lets the props be defined in a `const` or `static`. All of the props have to be `bool`s, `char`s,
+/// integers, floats or `&str`s, and the component can't be generic.
+/// * `default` - Implements [`Default`] for the generated props struct, so the component can be launched without
+/// passing props. Every prop has to be optional, i.e. an `Option`, `children`, or marked with `#[props(default)]`,
+/// `#[props(default = ...)]` or `#[props(optional)]`.
+/// * `doc = "path/to/doc.md"` - Uses the contents of a markdown file as the component's documentation, before any
+/// doc comments. The path is relative to the file the component is defined in, like with [`include_str!`].
+/// * `props = "MyProps"` - Names the generated props struct `MyProps` instead of the component name followed by `Props`.
+/// Useful when that name is already taken, or to build the props by hand under a clearer name.
 ///
 /// # Features
 /// This attribute:
@@ -115,6 +166,45 @@ pub(crate) const COMPONENT_ARG_CASE_CHECK_OFF: &str = "no_case_check";
 /// accidentally don't use `snake_case`
 /// for a variable name in the function, the compiler will still warn you.
 /// * Automatically uses `#[inline_props]` if there's more than 1 parameter in the function.
+/// The props can also be a hand-written struct in the `Scope` type, like `cx: Scope<LabelProps>`. With other parameters
+/// too, the hand-written struct is passed as a `props` prop, like `Card { props: LabelProps { .. }, footer: "bye" }`,
+/// and the body still gets it through `cx.props`.
+/// * `#[props(rename = "kind")]` on an argument names the prop, i.e. the props struct field and builder setter, `kind`,
+/// while the component body still uses the argument's name. Keywords can be used as raw identifiers, like `"r#type"`.
+/// * `#[props(renamed_from = "old_name")]` on a prop keeps a deprecated `old_name` setter on the props builder,
+/// which forwards to the renamed prop, so `rsx!` code using the old name still compiles with a warning.
+/// * `#[props(reactive)]` on a prop makes its setter take anything the prop implements `FromReactive` for. E.g. a
+/// `MaybeSignal<u32>` prop from `dioxus-signals` can be set to a plain `u32` or to a signal.
+/// * `children` is reserved for the nested content of a component, which `rsx!` passes as a single `Element`. A
+/// `children: Vec<Element>` prop can't be filled that way, but its builder setter takes any iterator of elements and
+/// collects it, like `List { children: (0..3).map(|i| render! { "item {i}" }) }`.
+/// * `Option` props also get a `maybe_` setter on the props builder, like `maybe_title(Option<String>)`,
+/// so an `Option` can be passed along without matching on it.
+/// * `#[props(default(desktop = 10, mobile = 20))]` on a prop gives it a different default on mobile (Android and iOS)
+/// than on every other target, including the web. Both values are required.
+/// * `#[props(variants = [Primary, Secondary])]` on an enum prop generates `is_primary` and `is_secondary`
+/// accessors on the props struct. The variants have to be listed, since a macro can't see the enum's definition.
+/// * Props marked with `#[props(skip_eq)]` are left out of the props struct's `PartialEq` impl, so props that can't be
+/// compared, like closures, don't stop the component from memoizing on the others.
+/// * Keeps `#[inline]`, `#[cold]` and `#[must_use]` on the component function only, not on the inner function
+/// that holds the body, so they apply where the component is called.
+/// * Accepts `Option<Element>` as the return type, where `None` renders nothing, so `?` can be used on `Option`s
+/// in the component body.
+/// * Accepts `async` components, like `async fn Profile(cx: Scope, id: u32) -> Element`. The body is polled while
+/// rendering, and the component suspends until it's ready, so the nearest suspense boundary renders in the meantime.
+/// A new future is polled on every render, since it borrows the scope, so await things that keep their progress
+/// outside of the body, like a value stored in a hook. See `ScopeState::render_async`.
+/// * A `key: Option<&str>` argument isn't a prop. `rsx!` uses the `key` of a component, like `Item { key: "{id}" }`,
+/// for keyed diffing, and the argument reads it back with `ScopeState::key`. Rename the argument with
+/// `#[props(rename = "...")]` to make it a regular prop.
+/// * An argument marked with `#[from_context]`, like `#[from_context] theme: Theme`, isn't a prop.
+/// It's left out of the props struct and read with `cx.consume_context` at the start of every render instead,
+/// which panics if no context of that type was provided.
+/// * Adds a hidden `__COMPONENT_SIGNATURE` constant with the component's name and props as a string, like
+/// `"Greeting(name: String, count: u32)"`, for devtools. It lives on the hidden `__dioxus_component_marker_` struct
+/// of the component, e.g. `__dioxus_component_marker_Greeting::__COMPONENT_SIGNATURE`, which every component has.
+/// * Adds a hidden `fn __new` to the generated props struct in test builds (`cfg(test)`), visible in the same crate,
+/// taking every prop in order, defaults included. It's meant for tests that build the props directly, without the builder.
 /// * Verifies the validity of your component.
 /// E.g. if it has a [`Scope`](dioxus_core::Scope) argument.
 /// Notes:
@@ -169,6 +259,7 @@ pub(crate) const COMPONENT_ARG_CASE_CHECK_OFF: &str = "no_case_check";
 ///     #[warn(non_snake_case)]
 ///     #[inline(always)]
 ///     fn __dx_inner_comp<'a>(cx: Scope<'a, GreetPersonProps>e) -> Element {
+///         #[allow(unused_variables)]
 ///         let GreetPersonProps { person } = &cx.props;
 ///         {
 ///             render! { "hello, {person}" }
@@ -182,19 +273,14 @@ pub(crate) const COMPONENT_ARG_CASE_CHECK_OFF: &str = "no_case_check";
 //  I think that's unnecessary, but there might be some scenario where it could be useful.
 #[proc_macro_attribute]
 pub fn component(args: TokenStream, input: TokenStream) -> TokenStream {
+    // rustc already reports an argument without a type, so expanding to nothing keeps that the only error.
+    if has_untyped_arg(input.clone().into()) {
+        return TokenStream::new();
+    }
     let component_body = parse_macro_input!(input as ComponentBody);
-    let case_check = match Punctuated::<Path, Token![,]>::parse_terminated.parse(args) {
-        Err(e) => return e.to_compile_error().into(),
-        Ok(args) => {
-            if let Some(first) = args.first() {
-                !first.is_ident(COMPONENT_ARG_CASE_CHECK_OFF)
-            } else {
-                true
-            }
-        }
-    };
+    let component_args = parse_macro_input!(args as ComponentDeserializerArgs);
 
-    match component_body.deserialize(ComponentDeserializerArgs { case_check }) {
+    match component_body.deserialize(SignatureDeserializerArgs { component_args }) {
         Err(e) => e.to_compile_error().into(),
         Ok(output) => output.to_token_stream().into(),
     }
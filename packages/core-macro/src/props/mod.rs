@@ -31,6 +31,7 @@ pub fn impl_my_derive(ast: &syn::DeriveInput) -> Result<TokenStream, Error> {
                     .map(|f| struct_info.required_field_impl(f))
                     .collect::<Result<Vec<_>, _>>()?;
                 let build_method = struct_info.build_method_impl();
+                let variant_accessors = struct_info.variant_accessors_impl()?;
 
                 quote! {
                     #builder_creation
@@ -38,6 +39,7 @@ pub fn impl_my_derive(ast: &syn::DeriveInput) -> Result<TokenStream, Error> {
                     #( #fields )*
                     #( #required_fields )*
                     #build_method
+                    #variant_accessors
                 }
             }
             syn::Fields::Unnamed(_) => {
@@ -160,6 +162,127 @@ mod util {
         }
         name
     }
+
+    /// Checks if the attribute is a doc comment, either plain or gated like `#[cfg_attr(doc, doc = "...")]`.
+    ///
+    /// A `cfg_attr` only counts if every attribute it expands to is a doc, so it can be copied to the setter as is.
+    pub fn is_doc_attr(attr: &syn::Attribute) -> bool {
+        if attr.path().is_ident("doc") {
+            return true;
+        }
+
+        if !attr.path().is_ident("cfg_attr") {
+            return false;
+        }
+
+        let Ok(metas) = attr.parse_args_with(
+            syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated,
+        ) else {
+            return false;
+        };
+
+        // The first meta is the predicate.
+        metas.len() > 1 && metas.iter().skip(1).all(|meta| meta.path().is_ident("doc"))
+    }
+
+    /// Converts a `PascalCase` identifier, like an enum variant, to `snake_case`.
+    pub fn to_snake_case(ident: &str) -> String {
+        let mut snake = String::with_capacity(ident.len() + 4);
+        for (i, c) in ident.chars().enumerate() {
+            if c.is_ascii_uppercase() {
+                if i > 0 {
+                    snake.push('_');
+                }
+                snake.push(c.to_ascii_lowercase());
+            } else {
+                snake.push(c);
+            }
+        }
+        snake
+    }
+
+    /// Checks if `tokens`, including any nested groups, contain one of `idents`.
+    pub fn tokens_contain_any_ident(
+        tokens: proc_macro2::TokenStream,
+        idents: &[&syn::Ident],
+    ) -> bool {
+        tokens.into_iter().any(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) => idents.iter().any(|i| **i == ident),
+            proc_macro2::TokenTree::Group(group) => {
+                tokens_contain_any_ident(group.stream(), idents)
+            }
+            _ => false,
+        })
+    }
+
+    /// The binding that a `self.field` reference in a default is rewritten to, see
+    /// [`rewrite_self_field_refs`].
+    pub fn self_field_binding(field: &syn::Ident) -> syn::Ident {
+        syn::Ident::new(
+            &format!("__self_{}", strip_raw_ident_prefix(field.to_string())),
+            field.span(),
+        )
+    }
+
+    /// Replaces every `self.field` in `tokens` with `(*__self_field)`, if `field` is one of `fields`.
+    ///
+    /// The caller binds `__self_field` to a reference to the field, so the rewritten expression
+    /// behaves like `self.field` does in a `&self` method: it can be read, borrowed or cloned,
+    /// but never moved out of. Returns the rewritten tokens and the fields that were referenced.
+    pub fn rewrite_self_field_refs(
+        tokens: proc_macro2::TokenStream,
+        fields: &[&syn::Ident],
+    ) -> (proc_macro2::TokenStream, Vec<syn::Ident>) {
+        let mut referenced = Vec::new();
+        let tokens = rewrite_self_field_refs_into(tokens, fields, &mut referenced);
+        (tokens, referenced)
+    }
+
+    fn rewrite_self_field_refs_into(
+        tokens: proc_macro2::TokenStream,
+        fields: &[&syn::Ident],
+        referenced: &mut Vec<syn::Ident>,
+    ) -> proc_macro2::TokenStream {
+        use proc_macro2::{Group, TokenTree};
+
+        let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+        let mut output = Vec::with_capacity(tokens.len());
+        let mut i = 0;
+
+        while i < tokens.len() {
+            if let [TokenTree::Ident(this), TokenTree::Punct(dot), TokenTree::Ident(field), ..] =
+                &tokens[i..]
+            {
+                if this == "self" && dot.as_char() == '.' && fields.iter().any(|f| *f == field) {
+                    let binding = self_field_binding(field);
+                    let mut deref = Group::new(
+                        proc_macro2::Delimiter::Parenthesis,
+                        quote::quote_spanned!(field.span()=> *#binding),
+                    );
+                    deref.set_span(field.span());
+                    output.push(TokenTree::Group(deref));
+                    if !referenced.contains(field) {
+                        referenced.push(field.clone());
+                    }
+                    i += 3;
+                    continue;
+                }
+            }
+
+            match &tokens[i] {
+                TokenTree::Group(group) => {
+                    let stream = rewrite_self_field_refs_into(group.stream(), fields, referenced);
+                    let mut new_group = Group::new(group.delimiter(), stream);
+                    new_group.set_span(group.span());
+                    output.push(TokenTree::Group(new_group));
+                }
+                token => output.push(token.clone()),
+            }
+            i += 1;
+        }
+
+        output.into_iter().collect()
+    }
 }
 
 mod field_info {
@@ -171,7 +294,8 @@ mod field_info {
     use syn::{parse::Error, punctuated::Punctuated};
 
     use super::util::{
-        expr_to_single_string, ident_to_type, path_to_single_string, strip_raw_ident_prefix,
+        expr_to_single_string, ident_to_type, is_doc_attr, path_to_single_string,
+        strip_raw_ident_prefix,
     };
 
     #[derive(Debug)]
@@ -181,6 +305,8 @@ mod field_info {
         pub generic_ident: syn::Ident,
         pub ty: &'a syn::Type,
         pub builder_attr: FieldBuilderAttr,
+        /// The field's doc comments, which are copied to its setter unless `#[props(doc = ...)]` is given.
+        pub doc_attrs: Vec<&'a syn::Attribute>,
     }
 
     impl<'a> FieldInfo<'a> {
@@ -219,6 +345,11 @@ mod field_info {
                     ),
                     ty: &field.ty,
                     builder_attr,
+                    doc_attrs: field
+                        .attrs
+                        .iter()
+                        .filter(|attr| is_doc_attr(attr))
+                        .collect(),
                 })
             } else {
                 Err(Error::new(field.span(), "Nameless field in struct"))
@@ -247,16 +378,37 @@ mod field_info {
         pub fn type_from_inside_option(&self, check_option_name: bool) -> Option<&syn::Type> {
             type_from_inside_option(self.ty, check_option_name)
         }
+
+        /// The item type of a `children: Vec<...>` prop, whose setter collects the children from any iterator.
+        pub fn children_vec_item_type(&self) -> Option<&syn::Type> {
+            if strip_raw_ident_prefix(self.name.to_string()) != "children" {
+                return None;
+            }
+            match self.ty {
+                syn::Type::Path(type_path) if type_path.path.segments.last()?.ident == "Vec" => {
+                    type_from_inside_option(self.ty, false)
+                }
+                _ => None,
+            }
+        }
     }
 
     #[derive(Debug, Default, Clone)]
     pub struct FieldBuilderAttr {
         pub default: Option<syn::Expr>,
         pub doc: Option<syn::Expr>,
+        pub validate: Option<syn::Expr>,
+        pub renamed_from: Option<syn::Ident>,
+        /// Variants of an enum prop that get an `is_<variant>` accessor on the props struct.
+        pub variants: Vec<syn::Ident>,
         pub skip: bool,
         pub auto_into: bool,
+        /// The setter takes anything the field can be built from with `FromReactive`, like a plain value or a signal.
+        pub reactive: bool,
         pub strip_option: bool,
         pub ignore_option: bool,
+        /// Only read by `#[component]`, which leaves the field out of the generated `PartialEq` impl.
+        pub skip_eq: bool,
     }
 
     impl FieldBuilderAttr {
@@ -309,6 +461,7 @@ mod field_info {
                     let name = expr_to_single_string(&assign.left)
                         .ok_or_else(|| Error::new_spanned(&assign.left, "Expected identifier"))?;
                     match name.as_str() {
+                        // `self.field` in the default refers to the final value of another field
                         "default" => {
                             self.default = Some(*assign.right);
                             Ok(())
@@ -317,6 +470,40 @@ mod field_info {
                             self.doc = Some(*assign.right);
                             Ok(())
                         }
+                        "validate" => {
+                            self.validate = Some(*assign.right);
+                            Ok(())
+                        }
+                        "variants" => {
+                            let syn::Expr::Array(array) = *assign.right else {
+                                return Err(Error::new_spanned(
+                                    assign.right,
+                                    "Expected a list of variants, like `[Primary, Secondary]`",
+                                ));
+                            };
+                            for variant in array.elems {
+                                let ident = match &variant {
+                                    syn::Expr::Path(path) => path.path.get_ident().cloned(),
+                                    _ => None,
+                                };
+                                self.variants.push(ident.ok_or_else(|| {
+                                    Error::new_spanned(&variant, "Expected identifier")
+                                })?);
+                            }
+                            Ok(())
+                        }
+                        "renamed_from" => {
+                            if let syn::Expr::Lit(syn::ExprLit {
+                                lit: syn::Lit::Str(old_name),
+                                ..
+                            }) = *assign.right
+                            {
+                                self.renamed_from = Some(old_name.parse()?);
+                                Ok(())
+                            } else {
+                                Err(Error::new_spanned(assign.right, "Expected string"))
+                            }
+                        }
                         "default_code" => {
                             if let syn::Expr::Lit(syn::ExprLit {
                                 lit: syn::Lit::Str(code),
@@ -387,12 +574,64 @@ mod field_info {
                             handle_fields!(
                                 "skip", skip, "skipped";
                                 "into", auto_into, "calling into() on the argument";
+                                "reactive", reactive, "reactive";
                                 "strip_option", strip_option, "putting the argument in Some(...)";
+                                "skip_eq", skip_eq, "skipped in PartialEq";
                             )
                         }
                     }
                 }
 
+                // #[props(default(desktop = 10, mobile = 20))]
+                syn::Expr::Call(call) => {
+                    let name = expr_to_single_string(&call.func)
+                        .ok_or_else(|| Error::new_spanned(&call.func, "Expected identifier"))?;
+                    if name != "default" {
+                        return Err(Error::new_spanned(
+                            &call.func,
+                            format!("Unknown parameter {name:?}"),
+                        ));
+                    }
+
+                    let mut desktop = None;
+                    let mut mobile = None;
+                    for arg in call.args.iter() {
+                        let syn::Expr::Assign(assign) = arg else {
+                            return Err(Error::new_spanned(
+                                arg,
+                                "Expected `desktop = ...` or `mobile = ...`",
+                            ));
+                        };
+                        match expr_to_single_string(&assign.left).as_deref() {
+                            Some("desktop") => desktop = Some(&assign.right),
+                            Some("mobile") => mobile = Some(&assign.right),
+                            _ => {
+                                return Err(Error::new_spanned(
+                                    &assign.left,
+                                    "Unknown platform, expected `desktop` or `mobile`",
+                                ))
+                            }
+                        }
+                    }
+
+                    let (Some(desktop), Some(mobile)) = (desktop, mobile) else {
+                        return Err(Error::new_spanned(
+                            &call,
+                            "Platform defaults need both a `desktop` and a `mobile` value",
+                        ));
+                    };
+
+                    // `mobile` is Android and iOS, `desktop` is every other target, including the web.
+                    self.default = Some(syn::parse_quote! {
+                        if ::core::cfg!(any(target_os = "android", target_os = "ios")) {
+                            #mobile
+                        } else {
+                            #desktop
+                        }
+                    });
+                    Ok(())
+                }
+
                 syn::Expr::Unary(syn::ExprUnary {
                     op: syn::UnOp::Not(_),
                     expr,
@@ -410,6 +649,18 @@ mod field_info {
                                 self.doc = None;
                                 Ok(())
                             }
+                            "validate" => {
+                                self.validate = None;
+                                Ok(())
+                            }
+                            "renamed_from" => {
+                                self.renamed_from = None;
+                                Ok(())
+                            }
+                            "variants" => {
+                                self.variants.clear();
+                                Ok(())
+                            }
                             "skip" => {
                                 self.skip = false;
                                 Ok(())
@@ -418,6 +669,14 @@ mod field_info {
                                 self.auto_into = false;
                                 Ok(())
                             }
+                            "reactive" => {
+                                self.reactive = false;
+                                Ok(())
+                            }
+                            "skip_eq" => {
+                                self.skip_eq = false;
+                                Ok(())
+                            }
                             "optional" => {
                                 self.strip_option = false;
                                 self.ignore_option = true;
@@ -475,7 +734,9 @@ mod struct_info {
     use super::field_info::{FieldBuilderAttr, FieldInfo};
     use super::util::{
         empty_type, empty_type_tuple, expr_to_single_string, make_punctuated_single,
-        modify_types_generics_hack, path_to_single_string, strip_raw_ident_prefix, type_tuple,
+        modify_types_generics_hack, path_to_single_string, rewrite_self_field_refs,
+        self_field_binding, strip_raw_ident_prefix, to_snake_case, tokens_contain_any_ident,
+        type_tuple,
     };
 
     #[derive(Debug)]
@@ -618,7 +879,7 @@ Finally, call `.build()` to create the instance of `{name}`.
                     .extend(predicates.predicates.clone());
             }
 
-            let can_memoize = match are_there_generics {
+            let can_memoize = match are_there_generics || self.builder_attr.no_memo {
                 true => quote! { false  },
                 false => quote! { self == other },
             };
@@ -711,15 +972,18 @@ Finally, call `.build()` to create the instance of `{name}`.
                 ref builder_name, ..
             } = *self;
 
-            let descructuring = self.included_fields().map(|f| {
-                if f.ordinal == field.ordinal {
-                    quote!(_)
-                } else {
-                    let name = f.name;
-                    quote!(#name)
-                }
-            });
-            let reconstructing = self.included_fields().map(|f| f.name);
+            let descructuring: Vec<_> = self
+                .included_fields()
+                .map(|f| {
+                    if f.ordinal == field.ordinal {
+                        quote!(_)
+                    } else {
+                        let name = f.name;
+                        quote!(#name)
+                    }
+                })
+                .collect();
+            let reconstructing: Vec<_> = self.included_fields().map(|f| f.name).collect();
 
             let mut ty_generics: Vec<syn::GenericArgument> = self
                 .generics
@@ -780,7 +1044,10 @@ Finally, call `.build()` to create the instance of `{name}`.
             let (impl_generics, _, where_clause) = generics.split_for_impl();
             let doc = match field.builder_attr.doc {
                 Some(ref doc) => quote!(#[doc = #doc]),
-                None => quote!(),
+                None => {
+                    let doc_attrs = &field.doc_attrs;
+                    quote!(#( #doc_attrs )*)
+                }
             };
 
             // NOTE: both auto_into and strip_option affect `arg_type` and `arg_expr`, but the order of
@@ -800,6 +1067,16 @@ Finally, call `.build()` to create the instance of `{name}`.
                     quote!(impl ::core::convert::Into<#arg_type>),
                     quote!(#field_name.into()),
                 )
+            } else if field.builder_attr.reactive {
+                (
+                    quote!(impl ::dioxus::core::IntoReactive<#arg_type>),
+                    quote!(::dioxus::core::IntoReactive::into_reactive(#field_name)),
+                )
+            } else if let Some(item_type) = field.children_vec_item_type() {
+                (
+                    quote!(impl ::core::iter::IntoIterator<Item = #item_type>),
+                    quote!(::core::iter::Iterator::collect(::core::iter::IntoIterator::into_iter(#field_name))),
+                )
             } else {
                 (quote!(#arg_type), quote!(#field_name))
             };
@@ -819,6 +1096,61 @@ Finally, call `.build()` to create the instance of `{name}`.
             );
             let repeated_fields_error_message = format!("Repeated field {field_name}");
 
+            // The old name of a renamed field keeps working, but warns and forwards to the new setter.
+            let renamed_setter = field.builder_attr.renamed_from.as_ref().map(|old_name| {
+                let note = format!(
+                    "Renamed to `{}`",
+                    strip_raw_ident_prefix(field_name.to_string())
+                );
+                quote! {
+                    #[deprecated(note = #note)]
+                    #[allow(clippy::type_complexity)]
+                    pub fn #old_name (self, #field_name: #arg_type) -> #builder_name < #( #target_generics ),* > {
+                        self.#field_name(#field_name)
+                    }
+                }
+            });
+
+            // Optional fields also get a `maybe_` setter, which takes the `Option` as is.
+            let maybe_setter = if field.builder_attr.strip_option {
+                let maybe_name = syn::Ident::new(
+                    &format!("maybe_{}", strip_raw_ident_prefix(field_name.to_string())),
+                    field_name.span(),
+                );
+                let inner_type = field.type_from_inside_option(false).ok_or_else(|| {
+                    Error::new_spanned(
+                        field_type,
+                        "can't `strip_option` - field is not `Option<...>`",
+                    )
+                })?;
+                let (maybe_type, maybe_expr) = if field.builder_attr.auto_into {
+                    (
+                        quote!(::core::option::Option<impl ::core::convert::Into<#inner_type>>),
+                        quote!(#field_name.map(::core::convert::Into::into)),
+                    )
+                } else {
+                    (quote!(#field_type), quote!(#field_name))
+                };
+                let maybe_doc = format!(
+                    "Sets `{}` from an `Option`, leaving it unset if it's `None`.",
+                    strip_raw_ident_prefix(field_name.to_string())
+                );
+                Some(quote! {
+                    #[doc = #maybe_doc]
+                    #[allow(clippy::type_complexity)]
+                    pub fn #maybe_name (self, #field_name: #maybe_type) -> #builder_name < #( #target_generics ),* > {
+                        let #field_name = (#maybe_expr,);
+                        let ( #(#descructuring,)* ) = self.fields;
+                        #builder_name {
+                            fields: ( #(#reconstructing,)* ),
+                            _phantom: self._phantom,
+                        }
+                    }
+                })
+            } else {
+                None
+            };
+
             Ok(quote! {
                 #[allow(dead_code, non_camel_case_types, missing_docs)]
                 impl #impl_generics #builder_name < #( #ty_generics ),* > #where_clause {
@@ -832,6 +1164,8 @@ Finally, call `.build()` to create the instance of `{name}`.
                             _phantom: self._phantom,
                         }
                     }
+                    #maybe_setter
+                    #renamed_setter
                 }
                 #[doc(hidden)]
                 #[allow(dead_code, non_camel_case_types, non_snake_case)]
@@ -957,6 +1291,63 @@ Finally, call `.build()` to create the instance of `{name}`.
             })
         }
 
+        /// Creates `is_<variant>` accessors for the variants listed in `#[props(variants = [...])]`.
+        ///
+        /// A proc macro can't see the definition of the enum, so the variants have to be listed explicitly,
+        /// and the field's type has to be a plain path to the enum.
+        pub fn variant_accessors_impl(&self) -> Result<TokenStream, Error> {
+            let mut accessors = Vec::new();
+
+            for field in &self.fields {
+                if field.builder_attr.variants.is_empty() {
+                    continue;
+                }
+
+                let syn::Type::Path(syn::TypePath { qself: None, path }) = field.ty else {
+                    return Err(Error::new_spanned(
+                        field.ty,
+                        "`variants` can only be used on props whose type is an enum, like `ButtonKind`",
+                    ));
+                };
+                // Patterns can't have generic arguments without a turbofish, so they're left out.
+                let mut enum_path = path.clone();
+                if let Some(segment) = enum_path.segments.last_mut() {
+                    segment.arguments = syn::PathArguments::None;
+                }
+
+                let field_name = field.name;
+                for variant in &field.builder_attr.variants {
+                    let method = syn::Ident::new(
+                        &format!("is_{}", to_snake_case(&variant.to_string())),
+                        variant.span(),
+                    );
+                    let doc = format!(
+                        "Checks if `{}` is the `{variant}` variant.",
+                        strip_raw_ident_prefix(field_name.to_string())
+                    );
+                    accessors.push(quote! {
+                        #[doc = #doc]
+                        pub fn #method(&self) -> bool {
+                            ::core::matches!(self.#field_name, #enum_path::#variant { .. })
+                        }
+                    });
+                }
+            }
+
+            if accessors.is_empty() {
+                return Ok(quote!());
+            }
+
+            let name = self.name;
+            let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+            Ok(quote! {
+                impl #impl_generics #name #ty_generics #where_clause {
+                    #( #accessors )*
+                }
+            })
+        }
+
         pub fn build_method_impl(&self) -> TokenStream {
             let StructInfo {
                 ref name,
@@ -1000,7 +1391,28 @@ Finally, call `.build()` to create the instance of `{name}`.
             });
             let (impl_generics, _, _) = generics.split_for_impl();
 
-            let (_, ty_generics, where_clause) = self.generics.split_for_impl();
+            let (_, ty_generics, _) = self.generics.split_for_impl();
+
+            // A `#[props(default)]` field whose type depends on a type parameter, like `T` or `Vec<T>`,
+            // can only be defaulted if that type implements `Default`, so the bound is added to `build`.
+            let type_params: Vec<_> = self.generics.type_params().map(|tp| &tp.ident).collect();
+            let mut build_generics = self.generics.clone();
+            for field in self.included_fields() {
+                let Some(ref default) = field.builder_attr.default else {
+                    continue;
+                };
+                let ty = field.ty;
+                if tokens_contain_any_ident(quote!(#ty), &type_params)
+                    && quote!(#default).to_string()
+                        == quote!(::core::default::Default::default()).to_string()
+                {
+                    build_generics
+                        .make_where_clause()
+                        .predicates
+                        .push(syn::parse_quote!(#ty: ::core::default::Default));
+                }
+            }
+            let (_, _, where_clause) = build_generics.split_for_impl();
 
             let modified_ty_generics = modify_types_generics_hack(&ty_generics, |args| {
                 args.insert(
@@ -1018,7 +1430,7 @@ Finally, call `.build()` to create the instance of `{name}`.
                 );
             });
 
-            let descructuring = self.included_fields().map(|f| f.name);
+            let descructuring: Vec<_> = self.included_fields().map(|f| f.name).collect();
 
             let helper_trait_name = &self.conversion_helper_trait_name;
             // The default of a field can refer to earlier-defined fields, which we handle by
@@ -1026,19 +1438,65 @@ Finally, call `.build()` to create the instance of `{name}`.
             // This means that field ordering may actually be significant, which isn’t ideal. We could
             // relax that restriction by calculating a DAG of field default dependencies and
             // reordering based on that, but for now this much simpler thing is a reasonable approach.
-            let assignments = self.fields.iter().map(|field| {
+            //
+            // Defaults that refer to other fields through `self.field` are deferred instead: they are
+            // evaluated after every other field has been set, so they see the final value of any field,
+            // wherever it is declared. Deferred defaults can only see earlier deferred fields.
+            //
+            // `self.field` is rewritten to go through a `__self_field` reference, so the default only
+            // borrows the field (just like it would in a `&self` method) and can't be confused with
+            // the builder's own locals.
+            let field_names: Vec<_> = self.fields.iter().map(|field| field.name).collect();
+            let mut assignments = Vec::with_capacity(self.fields.len());
+            let mut deferred_assignments = Vec::new();
+            for field in &self.fields {
                 let name = &field.name;
-                if let Some(ref default) = field.builder_attr.default {
-                    if field.builder_attr.skip {
-                        quote!(let #name = #default;)
-                    } else {
-                        quote!(let #name = #helper_trait_name::into_value(#name, || #default);)
+                match field.builder_attr.default {
+                    Some(ref default) => {
+                        let (default, referenced) =
+                            rewrite_self_field_refs(quote!(#default), &field_names);
+                        let default = if referenced.is_empty() {
+                            default
+                        } else {
+                            let bindings = referenced.iter().map(self_field_binding);
+                            quote!({
+                                #( let #bindings = &#referenced; )*
+                                #default
+                            })
+                        };
+                        let assignment = if field.builder_attr.skip {
+                            quote!(let #name = #default;)
+                        } else {
+                            quote!(let #name = #helper_trait_name::into_value(#name, || #default);)
+                        };
+                        if referenced.is_empty() {
+                            assignments.push(assignment);
+                        } else {
+                            deferred_assignments.push(assignment);
+                        }
                     }
-                } else {
-                    quote!(let #name = #name.0;)
+                    None => assignments.push(quote!(let #name = #name.0;)),
                 }
-            });
-            let field_names = self.fields.iter().map(|field| field.name);
+            }
+            assignments.extend(deferred_assignments);
+            // Validators run against the finished struct, so they can see the final value of
+            // defaulted fields and are checked in declaration order.
+            let validations: Vec<_> = self
+                .fields
+                .iter()
+                .filter_map(|field| {
+                    let name = field.name;
+                    let name_str = strip_raw_ident_prefix(name.to_string());
+                    field.builder_attr.validate.as_ref().map(|validate| {
+                        quote! {
+                            if let ::core::result::Result::Err(err) = (#validate)(&props.#name) {
+                                fields.push((#name_str, ::std::string::ToString::to_string(&err)));
+                            }
+                        }
+                    })
+                })
+                .collect();
+            let name_str = strip_raw_ident_prefix(name.to_string());
             let doc = if self.builder_attr.doc {
                 match self.builder_attr.build_method_doc {
                     Some(ref doc) => quote!(#[doc = #doc]),
@@ -1053,15 +1511,52 @@ Finally, call `.build()` to create the instance of `{name}`.
             } else {
                 quote!()
             };
+            // Only props with validators get a `try_build`, which `build` then unwraps, so the field
+            // assignments are only expanded once either way.
+            if validations.is_empty() {
+                return quote!(
+                    #[allow(dead_code, non_camel_case_types, missing_docs)]
+                    impl #impl_generics #builder_name #modified_ty_generics #where_clause {
+                        #doc
+                        pub fn build(self) -> #name #ty_generics {
+                            let ( #(#descructuring,)* ) = self.fields;
+                            #( #assignments )*
+                            #name {
+                                #( #field_names ),*
+                            }
+                        }
+                    }
+                );
+            }
+
+            let try_build_doc =
+                "Finalise the builder like `build`, but return a `PropsError` listing every field \
+                rejected by its `#[props(validate = ...)]` function instead of panicking";
             quote!(
-                #[allow(dead_code, non_camel_case_types, missing_docs)]
+                #[allow(dead_code, non_camel_case_types, missing_docs, clippy::panic)]
                 impl #impl_generics #builder_name #modified_ty_generics #where_clause {
                     #doc
                     pub fn build(self) -> #name #ty_generics {
+                        self.try_build()
+                            .unwrap_or_else(|err| ::core::panic!("{}", err))
+                    }
+
+                    #[doc = #try_build_doc]
+                    pub fn try_build(self) -> ::core::result::Result<#name #ty_generics, ::dioxus::core::PropsError> {
                         let ( #(#descructuring,)* ) = self.fields;
                         #( #assignments )*
-                        #name {
+                        let props = #name {
                             #( #field_names ),*
+                        };
+                        let mut fields = ::std::vec::Vec::new();
+                        #( #validations )*
+                        if fields.is_empty() {
+                            ::core::result::Result::Ok(props)
+                        } else {
+                            ::core::result::Result::Err(::dioxus::core::PropsError {
+                                props: #name_str,
+                                fields,
+                            })
                         }
                     }
                 }
@@ -1085,6 +1580,10 @@ Finally, call `.build()` to create the instance of `{name}`.
         /// specify `doc` instead and a default value will be filled in here.
         pub build_method_doc: Option<syn::Expr>,
 
+        /// Whether `memoize` always returns `false`, so the component re-renders whenever its parent does.
+        /// This also means the struct doesn't need to implement `PartialEq`.
+        pub no_memo: bool,
+
         pub field_defaults: FieldBuilderAttr,
     }
 
@@ -1092,7 +1591,10 @@ Finally, call `.build()` to create the instance of `{name}`.
         pub fn new(attrs: &[syn::Attribute]) -> Result<TypeBuilderAttr, Error> {
             let mut result = TypeBuilderAttr::default();
             for attr in attrs {
-                if path_to_single_string(attr.path()).as_deref() != Some("builder") {
+                if !matches!(
+                    path_to_single_string(attr.path()).as_deref(),
+                    Some("builder" | "props")
+                ) {
                     continue;
                 }
 
@@ -1153,6 +1655,10 @@ Finally, call `.build()` to create the instance of `{name}`.
                             self.doc = true;
                             Ok(())
                         }
+                        "no_memo" => {
+                            self.no_memo = true;
+                            Ok(())
+                        }
                         _ => Err(Error::new_spanned(
                             &path,
                             format!("Unknown parameter {name:?}"),
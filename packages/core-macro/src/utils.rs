@@ -47,6 +47,28 @@ pub fn format_type_string(ty: &Type) -> String {
     ty_formatted.to_string()
 }
 
+const SCALAR_TYPES: &[&str] = &[
+    "String", "bool", "char", "f32", "f64", "i8", "i16", "i32", "i64", "i128", "isize", "u8",
+    "u16", "u32", "u64", "u128", "usize",
+];
+
+/// Checks if the type is a string, `char`, `bool`, integer or float, judging by the last path segment.
+///
+/// This can't resolve type aliases, so it only recognizes the types by their usual names.
+pub fn is_scalar_type(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(reference) => reference.elem.to_token_stream().to_string() == "str",
+        Type::Path(type_path) => {
+            if let Some(segment) = type_path.path.segments.last() {
+                SCALAR_TYPES.contains(&segment.ident.to_string().as_str())
+            } else {
+                false
+            }
+        }
+        _ => false,
+    }
+}
+
 /// Represents the `#[deprecated]` attribute.
 ///
 /// You can use the [`DeprecatedAttribute::from_meta`] function to try to parse an attribute to this struct.
@@ -0,0 +1,53 @@
+use dioxus::prelude::*;
+use futures_channel::oneshot;
+
+#[component]
+async fn Profile(cx: Scope, name: String, visits: u32) -> Element {
+    // The receiver is kept in a hook, so it's still resolved when the component renders again.
+    let loaded = cx.use_hook(|| {
+        let (tx, rx) = oneshot::channel();
+        cx.spawn(async move {
+            let _ = tx.send(());
+        });
+        rx
+    });
+    let _ = loaded.await;
+
+    render! { "{name}: {visits}" }
+}
+
+#[component]
+async fn Nickname(cx: Scope, name: Option<String>) -> Option<Element> {
+    let name = name.as_ref()?;
+    Some(render! { "aka {name}" })
+}
+
+fn app(cx: Scope) -> Element {
+    render! {
+        div {
+            Profile { name: "bob".to_string(), visits: 3 }
+            Nickname { name: "bobby".to_string() }
+            Nickname {}
+        }
+    }
+}
+
+#[test]
+fn async_components_suspend_until_ready() {
+    tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap()
+        .block_on(async {
+            let mut dom = VirtualDom::new(app);
+            let edits = dom.rebuild();
+
+            // Nothing is awaited before `Nickname` renders, so only `Profile` is still pending.
+            assert!(!edits
+                .edits
+                .iter()
+                .any(|edit| format!("{edit:?}").contains("bob:")));
+
+            dom.wait_for_suspense().await;
+            assert_eq!(dioxus_ssr::render(&dom), "<div>bob: 3aka bobby</div>");
+        });
+}
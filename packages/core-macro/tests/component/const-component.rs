@@ -0,0 +1,10 @@
+// Given a `const fn` component, ensure the error points at the `const`.
+
+use dioxus::prelude::*;
+
+#[component]
+const fn Greeting(cx: Scope, name: String) -> Element {
+    render! { "hello, {name}" }
+}
+
+fn main() {}
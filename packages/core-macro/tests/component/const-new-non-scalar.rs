@@ -0,0 +1,10 @@
+// Given `const_new` and a prop that can't be created in a const context, ensure the error points at its type.
+
+use dioxus::prelude::*;
+
+#[component(const_new)]
+fn Badge(cx: Scope, count: u32, label: String) -> Element {
+    render! { "{label}: {count}" }
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+// Given `default` and a prop that has to be passed, ensure the error points at the prop.
+
+use dioxus::prelude::*;
+
+#[component(default)]
+fn Settings(cx: Scope, #[props(default)] volume: u32, name: String) -> Element {
+    render! { "{name}: {volume}" }
+}
+
+fn main() {}
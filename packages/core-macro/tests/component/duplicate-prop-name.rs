@@ -0,0 +1,11 @@
+// Given a component where a renamed prop collides with another prop,
+// ensure the error points at the rename.
+
+use dioxus::prelude::*;
+
+#[component]
+fn Input(cx: Scope, kind: String, #[props(rename = "kind")] variant: u8) -> Element {
+    render! { "{kind} {variant}" }
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+// Given a component with a prop renamed to `key`, ensure the error points at the new name.
+
+use dioxus::prelude::*;
+
+#[component]
+fn Item(cx: Scope, #[props(rename = "key")] id: String) -> Element {
+    render! { "{id}" }
+}
+
+fn main() {}
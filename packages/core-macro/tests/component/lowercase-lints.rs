@@ -0,0 +1,14 @@
+#![deny(warnings)]
+
+use dioxus::prelude::*;
+
+// The marker of a lowercase component doesn't warn about its name, but the body is still linted.
+#[component(no_case_check, marker)]
+fn lowercase_card(cx: Scope) -> Element {
+    let BadName = "card";
+    render! { "{BadName}" }
+}
+
+fn main() {
+    let _: Component = *lowercase_card {};
+}
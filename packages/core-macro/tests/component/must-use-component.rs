@@ -0,0 +1,18 @@
+// Given a component marked with #[must_use], ensure the lint fires where the component function is called.
+#![deny(unused_must_use)]
+
+use dioxus::prelude::*;
+
+#[component]
+#[must_use]
+fn Greeting(cx: Scope) -> Element {
+    render! { "hello" }
+}
+
+#[component]
+fn App(cx: Scope) -> Element {
+    Greeting(cx);
+    render! { Greeting {} }
+}
+
+fn main() {}
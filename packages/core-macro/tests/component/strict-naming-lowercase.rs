@@ -0,0 +1,10 @@
+// Given a lowercase component name with strict naming, ensure it's an error on the name.
+
+use dioxus::prelude::*;
+
+#[component(strict_naming)]
+fn app(cx: Scope) -> Element {
+    render! { "hello" }
+}
+
+fn main() {}
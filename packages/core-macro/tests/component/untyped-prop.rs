@@ -0,0 +1,10 @@
+// Given a component with an argument whose type is left to inference, ensure the error points at the argument.
+
+use dioxus::prelude::*;
+
+#[component]
+fn Label(cx: Scope, label: _) -> Element {
+    render! { "{label}" }
+}
+
+fn main() {}
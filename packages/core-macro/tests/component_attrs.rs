@@ -0,0 +1,99 @@
+//! Tests for how attributes on a component are forwarded by `#[component]`.
+
+use dioxus::prelude::*;
+
+#[test]
+fn codegen_attrs_apply_to_the_component() {
+    // The inner function is `#[inline(always)]`, so `#[inline]` or `#[cold]` copied onto it would be an unused
+    // attribute. `must_use` only ending up on the component is checked by the `must-use-component` ui test.
+    #[component]
+    #[deny(unused_attributes)]
+    #[inline]
+    #[must_use]
+    fn Greeting(cx: Scope, name: String) -> Element {
+        render! { "hello, {name}" }
+    }
+
+    #[component]
+    #[deny(unused_attributes)]
+    #[cold]
+    fn Fallback(cx: Scope) -> Element {
+        render! { "something went wrong" }
+    }
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        render! {
+            Greeting { name: "bob".to_string() }
+            Fallback {}
+        }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "hello, bobsomething went wrong");
+}
+
+#[test]
+fn cfg_gated_docs_are_forwarded() {
+    /// Always documented.
+    #[cfg_attr(doc, doc = "Only documented in rustdoc.")]
+    #[component]
+    fn Gated(
+        cx: Scope,
+        #[cfg_attr(all(), doc = "Always enabled.")] label: String,
+        #[cfg_attr(any(), doc = "Never enabled.")]
+        #[props(default)]
+        hidden: bool,
+    ) -> Element {
+        render! { "{label} {hidden}" }
+    }
+
+    let props = GatedProps::builder().label("save".to_string()).build();
+    assert_eq!(props.label, "save");
+    assert!(!props.hidden);
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        render! { Gated { label: "save".to_string() } }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "save false");
+}
+
+#[test]
+fn external_docs_are_included() {
+    /// Appended after the external docs.
+    #[component(doc = "component_doc.md")]
+    fn ExternallyDocumented(cx: Scope, name: String) -> Element {
+        render! { "hello, {name}" }
+    }
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        render! { ExternallyDocumented { name: "bob".to_string() } }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "hello, bob");
+}
+
+#[test]
+fn strict_naming_allows_acronyms() {
+    #[component(strict_naming)]
+    fn HTMLParser(cx: Scope, source: String) -> Element {
+        render! { "{source}" }
+    }
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        render! { HTMLParser { source: "html".to_string() } }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "html");
+}
@@ -0,0 +1,99 @@
+//! Tests for the ways component props can be constructed.
+
+use dioxus::prelude::*;
+
+#[test]
+fn all_optional_props_implement_default() {
+    #[component(default)]
+    fn Settings(
+        cx: Scope,
+        #[props(default)] volume: u32,
+        #[props(default = "dark".to_string())] theme: String,
+        nickname: Option<String>,
+    ) -> Element {
+        render! { "{volume} {theme} {nickname:?}" }
+    }
+
+    let props = SettingsProps::default();
+    assert_eq!(props.volume, 0);
+    assert_eq!(props.theme, "dark");
+    assert_eq!(props.nickname, None);
+
+    let mut dom = VirtualDom::new_with_props(Settings, props);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "0 dark None");
+}
+
+#[test]
+fn scalar_props_can_be_const() {
+    #[component(const_new)]
+    fn Badge(cx: Scope, count: u32, visible: bool, #[props(default)] scale: f32) -> Element {
+        render! { "{count} {visible} {scale}" }
+    }
+
+    const BADGE: BadgeProps = BadgeProps::new_const(3, true, 1.5);
+    assert_eq!(BADGE.count, 3);
+    assert!(BADGE.visible);
+    assert_eq!(BADGE.scale, 1.5);
+
+    let mut dom = VirtualDom::new_with_props(Badge, BADGE);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "3 true 1.5");
+}
+
+#[test]
+fn props_can_be_built_positionally() {
+    #[component]
+    fn Greeting(cx: Scope, name: String, #[props(default)] count: u32) -> Element {
+        render! { "hello, {name} x{count}" }
+    }
+
+    let built = GreetingProps::builder()
+        .name("bob".to_string())
+        .count(3)
+        .build();
+    let positional = GreetingProps::__new("bob".to_string(), 3);
+    assert!(built == positional);
+
+    let mut dom = VirtualDom::new_with_props(Greeting, positional);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "hello, bob x3");
+}
+
+#[test]
+fn non_exhaustive_props_are_built_with_the_builder() {
+    #[component(non_exhaustive)]
+    fn Avatar(cx: Scope, url: String, #[props(default)] size: u32) -> Element {
+        render! { "{url} {size}" }
+    }
+
+    let props = AvatarProps::builder().url("me.png".to_string()).build();
+    assert_eq!(props.url, "me.png");
+    assert_eq!(props.size, 0);
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        render! { Avatar { url: "me.png".to_string(), size: 32 } }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "me.png 32");
+}
+
+#[test]
+fn copy_props_can_be_copied() {
+    #[component(copy)]
+    fn Badge<'a>(cx: Scope<'a>, label: &'a str, count: u32) -> Element {
+        render! { "{label} {count}" }
+    }
+
+    let props = BadgeProps::builder().label("inbox").count(3).build();
+    let copied = props;
+    assert_eq!(props.label, copied.label);
+    assert_eq!(props.count, copied.count);
+
+    let mut dom = VirtualDom::new_with_props(Badge, props);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "inbox 3");
+}
@@ -0,0 +1,43 @@
+//! Tests for `#[from_context]` component arguments.
+
+use dioxus::prelude::*;
+
+#[derive(Clone, PartialEq, Debug)]
+struct Theme(&'static str);
+
+#[component]
+fn ThemedLabel(cx: Scope, text: String, #[from_context] theme: Theme) -> Element {
+    assert_eq!(theme, Theme("dark"));
+    render! { "{text}" }
+}
+
+#[test]
+fn context_args_are_not_props() {
+    let props = ThemedLabelProps::builder().text("save".to_string()).build();
+    assert_eq!(props.text, "save");
+    assert_eq!(ThemedLabelProps::PROPS_FIELD_COUNT, 1);
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        cx.provide_context(Theme("dark"));
+        render! { ThemedLabel { text: "save".to_string() } }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "save");
+}
+
+#[test]
+#[should_panic(
+    expected = "No context of type `Theme` was provided for the `theme` argument of `ThemedLabel`"
+)]
+fn missing_context_args_panic() {
+    #[component]
+    fn App(cx: Scope) -> Element {
+        render! { ThemedLabel { text: "save".to_string() } }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+}
@@ -0,0 +1,13 @@
+#[test]
+fn component_errors() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/component/const-component.rs");
+    t.compile_fail("tests/component/const-new-non-scalar.rs");
+    t.compile_fail("tests/component/default-required-prop.rs");
+    t.compile_fail("tests/component/duplicate-prop-name.rs");
+    t.compile_fail("tests/component/key-prop.rs");
+    t.compile_fail("tests/component/lowercase-lints.rs");
+    t.compile_fail("tests/component/must-use-component.rs");
+    t.compile_fail("tests/component/strict-naming-lowercase.rs");
+    t.compile_fail("tests/component/untyped-prop.rs");
+}
@@ -0,0 +1,158 @@
+//! Tests for generic components and their props.
+
+use dioxus::prelude::*;
+
+#[test]
+fn marker_generics_are_carried_by_phantom_data() {
+    struct Celsius;
+
+    #[component]
+    fn Temperature<Unit: 'static>(cx: Scope, value: f64) -> Element {
+        render! { "{value}" }
+    }
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        render! { Temperature::<Celsius> { value: 21.5 } }
+    }
+
+    let props = TemperatureProps::<Celsius>::builder().value(21.5).build();
+    assert_eq!(props.value, 21.5);
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "21.5");
+}
+
+#[test]
+fn generic_props_can_be_defaulted() {
+    #[component]
+    fn Counter<T: PartialEq + std::fmt::Display + 'static>(
+        cx: Scope,
+        label: String,
+        #[props(default)] count: T,
+    ) -> Element {
+        render! { "{label}: {count}" }
+    }
+
+    let props = CounterProps::<u32>::builder()
+        .label("clicks".to_string())
+        .build();
+    assert_eq!(props.count, 0);
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        render! { Counter::<u32> { label: "clicks".to_string() } }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "clicks: 0");
+}
+
+#[test]
+fn impl_trait_props_become_generics() {
+    #[component]
+    fn List(
+        cx: Scope,
+        items: impl IntoIterator<Item = String> + Clone + PartialEq + 'static,
+        title: impl std::fmt::Display + PartialEq + 'static,
+    ) -> Element {
+        let count = items.clone().into_iter().count();
+        render! { "{title}: {count}" }
+    }
+
+    // Each `impl Trait` prop gets its own generic parameter, so they can be different types.
+    let props = ListProps::builder()
+        .items(vec!["a".to_string(), "b".to_string()])
+        .title("Letters")
+        .build();
+    assert_eq!(props.items, ["a", "b"]);
+    assert_eq!(props.title, "Letters");
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        render! { List { items: vec!["a".to_string()], title: 1 } }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "1: 1");
+}
+
+#[test]
+fn generic_props_without_required_props_implement_default() {
+    #[component(default)]
+    fn Counter<T: PartialEq + std::fmt::Display + 'static>(
+        cx: Scope,
+        #[props(default)] count: T,
+    ) -> Element {
+        render! { "{count}" }
+    }
+
+    let props = CounterProps::<u32>::default();
+    assert_eq!(props.count, 0);
+
+    let mut dom = VirtualDom::new_with_props(Counter::<u32>, props);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "0");
+}
+
+#[test]
+fn where_clauses_bound_the_props() {
+    #[component]
+    fn Grid<T>(cx: Scope, data: Vec<T>, #[props(default)] columns: usize) -> Element
+    where
+        T: Clone + PartialEq + 'static,
+    {
+        let rows = data.clone();
+        render! { "{rows.len()} {columns}" }
+    }
+
+    let props = GridProps::builder().data(vec![1, 2, 3]).build();
+    assert_eq!(props.data, [1, 2, 3]);
+    assert_eq!(props.columns, 0);
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        render! { Grid { data: vec![1, 2, 3], columns: 2 } }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "3 2");
+}
+
+#[test]
+fn generic_defaults_are_kept_on_props() {
+    #[component]
+    fn Table<T: PartialEq + std::fmt::Display + 'static = String>(
+        cx: Scope,
+        rows: Vec<T>,
+    ) -> Element {
+        render! {
+            for row in rows {
+                "{row}"
+            }
+        }
+    }
+
+    // Without specifying `T`, the default is used.
+    let props: TableProps = TableProps::builder().rows(vec!["a".to_string()]).build();
+    assert_eq!(props.rows, ["a"]);
+
+    let props = TableProps::<u32>::builder().rows(vec![1, 2]).build();
+    assert_eq!(props.rows, [1, 2]);
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        render! {
+            Table { rows: vec!["a".to_string()] }
+            Table::<u32> { rows: vec![1, 2] }
+        }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "a12");
+}
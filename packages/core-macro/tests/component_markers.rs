@@ -0,0 +1,119 @@
+//! Tests for the marker structs `#[component]` generates next to a component.
+
+use dioxus::prelude::*;
+
+#[test]
+fn component_markers_forward_to_the_component() {
+    #[component(marker)]
+    fn First(cx: Scope) -> Element {
+        render! { "first" }
+    }
+
+    #[component(marker)]
+    fn Second(cx: Scope) -> Element {
+        render! { "second" }
+    }
+
+    let marker = First {};
+    let copied = marker;
+    assert_eq!(marker, copied);
+
+    let components: Vec<Component> = vec![*First {}, *Second {}];
+    for (component, expected) in components.into_iter().zip(["first", "second"]) {
+        let mut dom = VirtualDom::new(component);
+        _ = dom.rebuild();
+        assert_eq!(dioxus_ssr::render(&dom), expected);
+    }
+}
+
+mod same_named_type {
+    use dioxus::prelude::*;
+
+    /// A data type that shares its name with the component that renders it.
+    #[derive(PartialEq, Clone)]
+    pub struct Card {
+        pub title: String,
+    }
+
+    #[component]
+    pub fn Card(cx: Scope, card: Card) -> Element {
+        render! { "{card.title}" }
+    }
+
+    pub struct Tag {
+        pub label: String,
+    }
+
+    // The preview is generated on the hidden marker struct, so it doesn't clash with `Tag` either.
+    #[component(preview)]
+    pub fn Tag(cx: Scope, label: String) -> Element {
+        render! { "{label}" }
+    }
+}
+
+#[test]
+fn components_can_share_a_name_with_a_type() {
+    use same_named_type::Card;
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        render! {
+            Card {
+                card: Card { title: "hello".to_string() }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "hello");
+
+    let tag = same_named_type::Tag {
+        label: "new".to_string(),
+    };
+    assert_eq!(tag.label, "new");
+    assert!(dioxus::core::previews().any(|preview| preview.name == "Tag"));
+}
+
+#[test]
+fn components_expose_their_signature() {
+    #[component(marker)]
+    fn Greeting(cx: Scope, name: String, count: Option<u32>) -> Element {
+        render! { "hello, {name}" }
+    }
+
+    #[component(marker)]
+    fn Home(cx: Scope) -> Element {
+        render! { "home" }
+    }
+
+    assert!(Greeting::__COMPONENT_SIGNATURE.contains("name: String"));
+    assert!(Greeting::__COMPONENT_SIGNATURE.contains("count: Option<u32>"));
+    assert_eq!(
+        Greeting::__COMPONENT_SIGNATURE,
+        "Greeting(name: String, count: Option<u32>)"
+    );
+    assert_eq!(Home::__COMPONENT_SIGNATURE, "Home()");
+}
+
+#[test]
+fn components_without_a_marker_expose_their_signature() {
+    #[component]
+    fn Label(cx: Scope, text: String) -> Element {
+        render! { "{text}" }
+    }
+
+    #[component]
+    fn List<T: std::fmt::Display + PartialEq + 'static>(cx: Scope, items: Vec<T>) -> Element {
+        render! { "{items.len()}" }
+    }
+
+    assert_eq!(
+        __dioxus_component_marker_Label::__COMPONENT_SIGNATURE,
+        "Label(text: String)"
+    );
+    assert_eq!(
+        __dioxus_component_marker_List::__COMPONENT_SIGNATURE,
+        "List(items: Vec<T>)"
+    );
+}
@@ -0,0 +1,37 @@
+//! Tests for the previews `#[component(preview)]` registers.
+
+use dioxus::prelude::*;
+
+#[component(preview)]
+fn Badge(cx: Scope, label: String, count: u32, #[props(default)] highlighted: bool) -> Element {
+    render! { "{label}: {count} {highlighted}" }
+}
+
+#[test]
+fn previews_are_registered_and_render() {
+    let preview = dioxus::core::previews()
+        .find(|preview| preview.name == "Badge")
+        .expect("Badge preview to be registered");
+
+    // Required props get a placeholder, the rest keep their default.
+    let mut dom = VirtualDom::new(preview.render);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), ": 0 false");
+}
+
+// `!optional` makes `subtitle` required, so it's part of the example in the docs and the preview has to set it.
+#[component(preview)]
+fn Heading(cx: Scope, title: String, #[props(!optional)] subtitle: Option<String>) -> Element {
+    render! { "{title} {subtitle:?}" }
+}
+
+#[test]
+fn previews_fill_in_required_option_props() {
+    let preview = dioxus::core::previews()
+        .find(|preview| preview.name == "Heading")
+        .expect("Heading preview to be registered");
+
+    let mut dom = VirtualDom::new(preview.render);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), " None");
+}
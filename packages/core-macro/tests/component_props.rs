@@ -0,0 +1,367 @@
+//! Tests for how `#[component]` turns the arguments of a component into props.
+
+use dioxus::prelude::*;
+use std::rc::Rc;
+
+#[test]
+fn unused_props_do_not_warn() {
+    #[component]
+    #[deny(unused_variables)]
+    fn Greeting(cx: Scope, name: String, unused: u32) -> Element {
+        render! { "hello, {name}" }
+    }
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        render! { Greeting { name: "bob".to_string(), unused: 0 } }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "hello, bob");
+}
+
+#[test]
+fn components_can_borrow_props() {
+    #[component]
+    fn TodoEntry<'a>(cx: Scope<'a>, todos: &'a UseState<Vec<String>>, id: usize) -> Element {
+        let todo = &todos.get()[*id];
+        render! { li { "{todo}" } }
+    }
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        let todos = use_state(cx, || vec!["write tests".to_string()]);
+        render! { TodoEntry { todos: todos, id: 0 } }
+    }
+
+    // The props struct has the component's lifetime, like a hand-written `TodoEntryProps<'a>` would.
+    fn borrowed_todos<'a>(props: &TodoEntryProps<'a>) -> &'a UseState<Vec<String>> {
+        props.todos
+    }
+    let _ = borrowed_todos;
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "<li>write tests</li>");
+}
+
+#[test]
+fn renamed_props_keep_their_binding() {
+    #[component]
+    fn Input(
+        cx: Scope,
+        #[props(rename = "r#type")] input_type: String,
+        #[props(rename = "kind", default)] variant: u8,
+    ) -> Element {
+        render! { "{input_type} {variant}" }
+    }
+
+    let props = InputProps::builder()
+        .r#type("checkbox".to_string())
+        .kind(2)
+        .build();
+    assert_eq!(props.r#type, "checkbox");
+    assert_eq!(props.kind, 2);
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        render! { Input { r#type: "text".to_string() } }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "text 0");
+}
+
+#[test]
+fn cfg_gated_props_can_be_disabled() {
+    #[component]
+    fn Toggle(
+        cx: Scope,
+        label: String,
+        #[cfg(all())] enabled: bool,
+        #[cfg(any())] missing: bool,
+    ) -> Element {
+        render! { "{label} {enabled}" }
+    }
+
+    let props = ToggleProps::builder()
+        .label("wifi".to_string())
+        .enabled(true)
+        .build();
+    assert!(props.enabled);
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        render! { Toggle { label: "wifi".to_string(), enabled: false } }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "wifi false");
+}
+
+#[test]
+fn skip_eq_props_are_not_compared() {
+    #[component]
+    fn Button(cx: Scope, label: String, #[props(skip_eq)] onpress: Rc<dyn Fn()>) -> Element {
+        render! { "{label}" }
+    }
+
+    let first = ButtonProps::builder()
+        .label("save".to_string())
+        .onpress(Rc::new(|| {}) as Rc<dyn Fn()>)
+        .build();
+    let second = ButtonProps::builder()
+        .label("save".to_string())
+        .onpress(Rc::new(|| println!("saved")) as Rc<dyn Fn()>)
+        .build();
+    let third = ButtonProps::builder()
+        .label("cancel".to_string())
+        .onpress(Rc::new(|| {}) as Rc<dyn Fn()>)
+        .build();
+
+    assert!(first == second);
+    assert!(first != third);
+}
+
+#[test]
+fn into_props_accept_convertible_values() {
+    #[component]
+    fn Button(
+        cx: Scope,
+        #[props(into)] label: String,
+        #[props(into)] tooltip: Option<String>,
+        #[props(default, into)] class: String,
+    ) -> Element {
+        let tooltip = tooltip.as_deref().unwrap_or("none");
+        render! { "{label} {tooltip} [{class}]" }
+    }
+
+    let props = ButtonProps::builder()
+        .label("Save")
+        .tooltip("Saves")
+        .build();
+    assert_eq!(props.label, "Save");
+    assert_eq!(props.tooltip.as_deref(), Some("Saves"));
+    assert_eq!(props.class, "");
+
+    let props = ButtonProps::builder()
+        .label("Save")
+        .class("primary")
+        .build();
+    assert_eq!(props.tooltip, None);
+    assert_eq!(props.class, "primary");
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        render! { Button { label: "Save", tooltip: "Saves" } }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "Save Saves []");
+}
+
+#[test]
+fn only_mut_props_are_rebound_mutably() {
+    #[component]
+    #[deny(unused_mut)]
+    fn Counter(cx: Scope, label: String, mut count: u32) -> Element {
+        // `count` is still a reference to the prop, so only where it points can change.
+        if *count > 10 {
+            count = &10;
+        }
+        render! { "{label}: {count}" }
+    }
+
+    let props = CounterProps::builder()
+        .label("clicks".to_string())
+        .count(1)
+        .build();
+    assert_eq!(props.count, 1);
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        render! { Counter { label: "clicks".to_string(), count: 12 } }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "clicks: 10");
+}
+
+#[test]
+fn option_element_components_can_render_nothing() {
+    #[component]
+    fn MaybeGreeting(cx: Scope, name: Option<String>) -> Option<Element> {
+        let name = name.as_ref()?;
+        Some(render! { "hello, {name}" })
+    }
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        render! {
+            div { MaybeGreeting { name: "bob".to_string() } }
+            div { MaybeGreeting {} }
+        }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "<div>hello, bob</div><div></div>");
+}
+
+#[test]
+fn no_memo_components_accept_non_comparable_props() {
+    struct Opaque(u32);
+
+    #[component(no_memo)]
+    fn Show(cx: Scope, value: Opaque) -> Element {
+        render! { "{value.0}" }
+    }
+
+    let props = ShowProps::builder().value(Opaque(1)).build();
+    assert!(!unsafe { props.memoize(&props) });
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        render! { Show { value: Opaque(1) } }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "1");
+}
+
+#[test]
+fn props_structs_can_be_renamed() {
+    #[component(props = "PrimaryButtonProps")]
+    fn Button(cx: Scope, label: String) -> Element {
+        render! { button { "{label}" } }
+    }
+
+    let props = PrimaryButtonProps::builder()
+        .label("save".to_string())
+        .build();
+    assert_eq!(props.label, "save");
+
+    let mut dom = VirtualDom::new_with_props(Button, props);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "<button>save</button>");
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        render! { Button { label: "cancel".to_string() } }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "<button>cancel</button>");
+}
+
+#[test]
+fn components_can_take_a_props_struct_in_the_scope() {
+    #[derive(Props, PartialEq)]
+    struct LabelProps {
+        text: String,
+    }
+
+    #[component(marker)]
+    fn Label(cx: Scope<LabelProps>) -> Element {
+        render! { "{cx.props.text}" }
+    }
+
+    #[component]
+    fn Caption(cx: Scope, text: String) -> Element {
+        render! { Label { text: text.clone() } }
+    }
+
+    let _: Component<LabelProps> = *Label {};
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        render! { Caption { text: "hello".to_string() } }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "hello");
+}
+
+#[test]
+fn components_can_mix_a_props_struct_in_the_scope_with_prop_arguments() {
+    #[derive(Props, PartialEq)]
+    struct TitleProps {
+        title: String,
+    }
+
+    #[component]
+    fn Card(cx: Scope<TitleProps>, footer: String) -> Element {
+        render! { "{cx.props.title} / {footer}" }
+    }
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        render! {
+            Card {
+                props: TitleProps { title: "hello".to_string() },
+                footer: "bye".to_string(),
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+    assert_eq!(dioxus_ssr::render(&dom), "hello / bye");
+}
+
+#[test]
+fn keys_drive_diffing_instead_of_props() {
+    use dioxus::core::DynProps;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CREATED: AtomicUsize = AtomicUsize::new(0);
+
+    #[component(reflect)]
+    fn Item(cx: Scope, key: Option<&str>, label: String) -> Element {
+        let created = *cx.use_hook(|| CREATED.fetch_add(1, Ordering::SeqCst));
+        let key = key.unwrap_or_default();
+        render! { li { "{key}{label}{created}" } }
+    }
+
+    // `key` isn't a prop, it's read from the scope.
+    let props = ItemProps::builder().label(":".to_string()).build();
+    assert_eq!(props.field_names(), ["label"]);
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        let ids: &[u32] = if cx.generation() % 2 == 0 {
+            &[1, 2, 3]
+        } else {
+            &[3, 2, 1]
+        };
+
+        render! {
+            ul {
+                ids.iter().map(|id| rsx! { Item { key: "{id}", label: ":".to_string() } })
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        "<ul><li>1:0</li><li>2:1</li><li>3:2</li></ul>"
+    );
+
+    // The items are matched by key, so reversing them moves the items along with their state.
+    dom.mark_dirty(ScopeId::ROOT);
+    _ = dom.render_immediate();
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        "<ul><li>3:2</li><li>2:1</li><li>1:0</li></ul>"
+    );
+}
@@ -0,0 +1,143 @@
+//! Tests for the reflection helpers generated on component props.
+
+use dioxus::prelude::*;
+
+#[test]
+fn display_summarizes_scalar_props() {
+    #[component(display)]
+    fn Profile(cx: Scope, name: String, age: u32, admin: bool, tags: Vec<String>) -> Element {
+        render! { "{name}" }
+    }
+
+    let props = ProfileProps::builder()
+        .name("bob".to_string())
+        .age(42)
+        .admin(false)
+        .tags(vec!["hidden".to_string()])
+        .build();
+
+    assert_eq!(
+        props.to_string(),
+        r#"Profile { name: "bob", age: 42, admin: false }"#
+    );
+}
+
+#[test]
+fn props_field_count_matches_parameters() {
+    #[component(reflect)]
+    fn Card<'a>(
+        cx: Scope<'a>,
+        title: String,
+        #[props(default)] subtitle: String,
+        children: Element<'a>,
+    ) -> Element {
+        render! { "{title} {subtitle}" children }
+    }
+
+    #[component(reflect)]
+    fn Tagged<Tag: 'static>(cx: Scope, value: u32) -> Element {
+        render! { "{value}" }
+    }
+
+    assert_eq!(CardProps::PROPS_FIELD_COUNT, 3);
+    assert_eq!(TaggedProps::<()>::PROPS_FIELD_COUNT, 1);
+}
+
+#[test]
+fn props_field_count_leaves_out_disabled_and_context_props() {
+    use dioxus::core::DynProps;
+
+    #[derive(Clone)]
+    struct Theme;
+
+    #[component(reflect)]
+    fn Panel(
+        cx: Scope,
+        title: String,
+        #[cfg(any())] debug_label: String,
+        #[from_context] theme: Theme,
+    ) -> Element {
+        let _ = theme;
+        render! { "{title}" }
+    }
+
+    let props = PanelProps::builder().title("panel".to_string()).build();
+    assert_eq!(PanelProps::PROPS_FIELD_COUNT, props.field_names().len());
+    assert_eq!(PanelProps::PROPS_FIELD_COUNT, 1);
+}
+
+#[test]
+fn schema_version_changes_with_props() {
+    mod before {
+        use dioxus::prelude::*;
+
+        #[component(reflect)]
+        pub fn Profile(cx: Scope, name: String) -> Element {
+            render! { "{name}" }
+        }
+    }
+
+    mod after {
+        use dioxus::prelude::*;
+
+        #[component(reflect)]
+        pub fn Profile(cx: Scope, name: String, age: u32) -> Element {
+            render! { "{name} {age}" }
+        }
+    }
+
+    mod same {
+        use dioxus::prelude::*;
+
+        #[component(reflect)]
+        pub fn Profile(cx: Scope, name: String) -> Element {
+            render! { "hello {name}" }
+        }
+    }
+
+    assert_ne!(
+        before::ProfileProps::schema_version(),
+        after::ProfileProps::schema_version()
+    );
+    assert_eq!(
+        before::ProfileProps::schema_version(),
+        same::ProfileProps::schema_version()
+    );
+
+    // A prop that isn't compiled in isn't part of the schema.
+    mod disabled {
+        use dioxus::prelude::*;
+
+        #[component(reflect)]
+        pub fn Profile(cx: Scope, name: String, #[cfg(any())] age: u32) -> Element {
+            render! { "{name}" }
+        }
+    }
+
+    assert_eq!(
+        before::ProfileProps::schema_version(),
+        disabled::ProfileProps::schema_version()
+    );
+}
+
+#[test]
+fn props_list_their_field_names() {
+    use dioxus::core::DynProps;
+
+    #[component(reflect)]
+    fn Greeting(
+        cx: Scope,
+        name: String,
+        #[props(rename = "kind")] variant: u8,
+        #[props(default)] count: u32,
+    ) -> Element {
+        render! { "{name} {variant} {count}" }
+    }
+
+    let props = GreetingProps::builder()
+        .name("bob".to_string())
+        .kind(1)
+        .build();
+    let dyn_props: &dyn DynProps = props.as_dyn_props();
+    assert_eq!(dyn_props.field_names(), ["name", "kind", "count"]);
+}
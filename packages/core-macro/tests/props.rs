@@ -0,0 +1,226 @@
+use dioxus::prelude::*;
+
+fn not_empty(value: &String) -> Result<(), &'static str> {
+    if value.is_empty() {
+        Err("must not be empty")
+    } else {
+        Ok(())
+    }
+}
+
+fn at_most_ten(value: &u32) -> Result<(), String> {
+    if *value > 10 {
+        Err(format!("must be at most 10, got {value}"))
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Props, PartialEq, Debug)]
+struct ValidatedProps {
+    #[props(validate = not_empty)]
+    name: String,
+    #[props(default, validate = at_most_ten)]
+    count: u32,
+}
+
+#[test]
+fn try_build_runs_validators() {
+    let props = ValidatedProps::builder()
+        .name("bob".to_string())
+        .try_build()
+        .unwrap();
+    assert_eq!(
+        props,
+        ValidatedProps {
+            name: "bob".to_string(),
+            count: 0
+        }
+    );
+
+    let err = ValidatedProps::builder()
+        .name(String::new())
+        .count(11)
+        .try_build()
+        .unwrap_err();
+    assert_eq!(err.props, "ValidatedProps");
+    assert_eq!(
+        err.fields,
+        vec![
+            ("name", "must not be empty".to_string()),
+            ("count", "must be at most 10, got 11".to_string()),
+        ]
+    );
+    assert_eq!(
+        err.to_string(),
+        "invalid ValidatedProps: `name` must not be empty, `count` must be at most 10, got 11"
+    );
+}
+
+#[test]
+#[should_panic(expected = "invalid ValidatedProps: `count` must be at most 10, got 12")]
+fn build_panics_on_validation_failure() {
+    ValidatedProps::builder()
+        .name("bob".to_string())
+        .count(12)
+        .build();
+}
+
+#[derive(Props, PartialEq, Debug)]
+struct RectProps {
+    #[props(default = self.width * 2)]
+    height: u32,
+    width: u32,
+    #[props(default = self.height.to_string())]
+    label: String,
+}
+
+#[test]
+fn defaults_can_reference_other_fields() {
+    let props = RectProps::builder().width(3).build();
+    assert_eq!(
+        props,
+        RectProps {
+            height: 6,
+            width: 3,
+            label: "6".to_string()
+        }
+    );
+
+    let props = RectProps::builder().width(3).height(4).build();
+    assert_eq!(props.height, 4);
+    assert_eq!(props.label, "4");
+}
+
+#[derive(Props, PartialEq, Debug)]
+struct NameProps {
+    #[props(default = format!("{} {}", self.first, self.last))]
+    full_name: String,
+    first: String,
+    last: String,
+    #[props(default = self.first.clone())]
+    nickname: String,
+}
+
+#[test]
+fn defaults_can_reference_non_copy_fields() {
+    let props = NameProps::builder()
+        .first("Ada".to_string())
+        .last("Lovelace".to_string())
+        .build();
+    assert_eq!(
+        props,
+        NameProps {
+            full_name: "Ada Lovelace".to_string(),
+            first: "Ada".to_string(),
+            last: "Lovelace".to_string(),
+            nickname: "Ada".to_string(),
+        }
+    );
+}
+
+#[derive(Props, PartialEq)]
+struct DocumentedProps {
+    /// The visible text
+    ///
+    /// Shown on the button itself.
+    label: String,
+    /// Overridden by the explicit setter doc
+    #[props(default, doc = "Whether the button can be pressed")]
+    disabled: bool,
+}
+
+#[test]
+fn documented_fields_keep_their_setters() {
+    let props = DocumentedProps::builder()
+        .label("save".to_string())
+        .disabled(true)
+        .build();
+    assert_eq!(props.label, "save");
+    assert!(props.disabled);
+}
+
+#[derive(Props, PartialEq, Debug)]
+struct RenamedProps {
+    #[props(renamed_from = "title")]
+    label: String,
+}
+
+#[test]
+#[allow(deprecated)]
+fn renamed_fields_accept_the_old_name() {
+    let new = RenamedProps::builder().label("save".to_string()).build();
+    let old = RenamedProps::builder().title("save".to_string()).build();
+    assert_eq!(new, old);
+}
+
+#[derive(PartialEq, Debug)]
+enum ButtonKind {
+    Primary,
+    Secondary,
+    IconOnly(&'static str),
+}
+
+#[derive(Props, PartialEq)]
+struct KindProps {
+    #[props(variants = [Primary, Secondary, IconOnly])]
+    kind: ButtonKind,
+}
+
+#[test]
+fn variant_accessors_match_the_listed_variants() {
+    let primary = KindProps::builder().kind(ButtonKind::Primary).build();
+    assert!(primary.is_primary());
+    assert!(!primary.is_secondary());
+    assert!(!primary.is_icon_only());
+
+    let icon = KindProps::builder()
+        .kind(ButtonKind::IconOnly("save"))
+        .build();
+    assert!(icon.is_icon_only());
+}
+
+#[derive(Props, PartialEq)]
+struct ListProps {
+    #[props(default(desktop = 10, mobile = 20))]
+    page_size: usize,
+}
+
+#[test]
+fn platform_defaults_depend_on_the_target() {
+    let props = ListProps::builder().build();
+    if cfg!(any(target_os = "android", target_os = "ios")) {
+        assert_eq!(props.page_size, 20);
+    } else {
+        assert_eq!(props.page_size, 10);
+    }
+
+    let props = ListProps::builder().page_size(5).build();
+    assert_eq!(props.page_size, 5);
+}
+
+#[derive(Props, PartialEq, Debug)]
+struct TooltipProps {
+    text: String,
+    title: Option<String>,
+    #[props(into)]
+    delay: Option<u64>,
+}
+
+#[test]
+fn optional_fields_accept_options() {
+    let title = Some("Save".to_string());
+    let props = TooltipProps::builder()
+        .text("save the file".to_string())
+        .maybe_title(title)
+        .maybe_delay(Some(200u32))
+        .build();
+    assert_eq!(props.title.as_deref(), Some("Save"));
+    assert_eq!(props.delay, Some(200));
+
+    let props = TooltipProps::builder()
+        .text("save the file".to_string())
+        .maybe_title(None)
+        .build();
+    assert_eq!(props.title, None);
+}
@@ -0,0 +1,21 @@
+//! Detect whether the compiler understands the `diagnostic` attribute namespace (Rust 1.78+)
+
+use std::{env, process::Command};
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=RUSTC");
+    println!("cargo:rustc-check-cfg=cfg(dioxus_diagnostic_namespace)");
+
+    if rustc_minor_version().map_or(false, |minor| minor >= 78) {
+        println!("cargo:rustc-cfg=dioxus_diagnostic_namespace");
+    }
+}
+
+/// The minor version of the compiler, like `78` for `rustc 1.78.0 (9b00956e5 2024-04-29)`
+fn rustc_minor_version() -> Option<u32> {
+    let rustc = env::var_os("RUSTC")?;
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+    let version = String::from_utf8(output.stdout).ok()?;
+    let mut parts = version.strip_prefix("rustc 1.")?.split('.');
+    parts.next()?.parse().ok()
+}
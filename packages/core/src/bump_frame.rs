@@ -1,6 +1,7 @@
 use crate::nodes::RenderReturn;
 use crate::{Attribute, AttributeValue};
 use bumpalo::Bump;
+use rustc_hash::FxHashSet;
 use std::cell::RefCell;
 use std::cell::{Cell, UnsafeCell};
 
@@ -8,6 +9,10 @@ pub(crate) struct BumpFrame {
     pub bump: UnsafeCell<Bump>,
     pub node: Cell<*const RenderReturn<'static>>,
     pub(crate) attributes_to_drop_before_reset: RefCell<Vec<*const Attribute<'static>>>,
+    /// The attribute text allocated in this frame's bump, so identical text is only allocated once per render
+    pub(crate) interned_text: RefCell<FxHashSet<&'static str>>,
+    /// Scratch space to format attribute text into before it's looked up in `interned_text`
+    pub(crate) text_buffer: RefCell<String>,
 }
 
 impl BumpFrame {
@@ -17,6 +22,8 @@ impl BumpFrame {
             bump: UnsafeCell::new(bump),
             node: Cell::new(std::ptr::null()),
             attributes_to_drop_before_reset: Default::default(),
+            interned_text: Default::default(),
+            text_buffer: Default::default(),
         }
     }
 
@@ -49,6 +56,8 @@ impl BumpFrame {
                 _ = l.take();
             }
         });
+        // The interned text points into the bump, so it has to go before the bump is reset
+        self.interned_text.borrow_mut().clear();
         unsafe {
             let bump = &mut *self.bump.get();
             bump.reset();
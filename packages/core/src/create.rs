@@ -313,6 +313,8 @@ impl<'b> VirtualDom {
                     id,
                 })
             }
+            // A `None` or falsey boolean attribute is left out, which a new element already does
+            _ if attribute.is_omitted() => {}
             _ => {
                 // Safety: we promise not to re-alias this text later on after committing it to the mutation
                 let value: BorrowedAttributeValue<'b> = (&attribute.value).into();
@@ -508,7 +510,7 @@ impl<'b> VirtualDom {
         use RenderReturn::*;
 
         // Load up a ScopeId for this vcomponent
-        let scope = self.load_scope_from_vcomponent(component);
+        let scope = self.load_scope_from_vcomponent(template, component);
 
         component.scope.set(Some(scope));
 
@@ -520,13 +522,20 @@ impl<'b> VirtualDom {
     }
 
     /// Load a scope from a vcomponent. If the props don't exist, that means the component is currently "live"
-    fn load_scope_from_vcomponent(&mut self, component: &VComponent) -> ScopeId {
+    fn load_scope_from_vcomponent<'a>(
+        &mut self,
+        template: &VNode<'a>,
+        component: &VComponent<'a>,
+    ) -> ScopeId {
         component
             .props
             .take()
             .map(|props| {
                 let unbounded_props: Box<dyn AnyProps> = unsafe { std::mem::transmute(props) };
-                self.new_scope(unbounded_props, component.name).context().id
+                let key = component_key(template, component);
+                self.new_scope(unbounded_props, component.name, key)
+                    .context()
+                    .id
             })
             .unwrap_or_else(|| component.scope.get().unwrap())
     }
@@ -573,3 +582,16 @@ fn collect_dyn_node_range(
 
     Some((start, end))
 }
+
+/// The key of a component, which is the key of its template when the component is the template's first root.
+///
+/// A component deeper in the template doesn't get a key, since the key belongs to the node that holds it.
+fn component_key<'a>(template: &VNode<'a>, component: &VComponent<'a>) -> Option<&'a str> {
+    match template.template.get().roots.first() {
+        Some(Dynamic { id }) => match &template.dynamic_nodes[*id] {
+            DynamicNode::Component(root) if std::ptr::eq(root, component) => template.key,
+            _ => None,
+        },
+        _ => None,
+    }
+}
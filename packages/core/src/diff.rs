@@ -165,7 +165,12 @@ impl<'b> VirtualDom {
 
     fn update_attribute(&mut self, right_attr: &'b Attribute<'b>, left_attr: &'b Attribute) {
         let name = unsafe { std::mem::transmute(left_attr.name) };
-        let value: BorrowedAttributeValue<'b> = (&right_attr.value).into();
+        // A `None` or falsey boolean attribute is removed instead of being set to its value
+        let value: BorrowedAttributeValue<'b> = if right_attr.is_omitted() {
+            BorrowedAttributeValue::None
+        } else {
+            (&right_attr.value).into()
+        };
         let value = unsafe { std::mem::transmute(value) };
         self.mutations.push(Mutation::SetAttribute {
             id: left_attr.mounted_element.get(),
@@ -1,6 +1,9 @@
-use crate::{runtime::with_runtime, ScopeId};
+use crate::{runtime::with_runtime, ScopeId, ScopeState};
+use bumpalo::{boxed::Box as BumpBox, Bump};
 use std::{
     cell::{Cell, RefCell},
+    future::Future,
+    panic::Location,
     rc::Rc,
 };
 
@@ -138,6 +141,11 @@ impl<T: std::fmt::Debug> std::fmt::Debug for Event<T> {
 pub struct EventHandler<'bump, T = ()> {
     pub(crate) origin: ScopeId,
     pub(super) callback: RefCell<Option<ExternalListenerCallback<'bump, T>>>,
+    /// Whether the callback is released after it's called, see [`EventHandler::once`].
+    pub(crate) once: Cell<bool>,
+    /// Where the handler was created. Only tracked in debug builds, since it's only useful for debugging.
+    #[cfg(debug_assertions)]
+    pub(crate) created_at: Option<&'static Location<'static>>,
 }
 
 impl<T> Default for EventHandler<'_, T> {
@@ -145,13 +153,56 @@ impl<T> Default for EventHandler<'_, T> {
         Self {
             origin: ScopeId::ROOT,
             callback: Default::default(),
+            once: Cell::new(false),
+            #[cfg(debug_assertions)]
+            created_at: None,
         }
     }
 }
 
+/// Prints whether a callback is set, like `EventHandler { set: true }`, since the callback itself can't be formatted.
+impl<T> std::fmt::Debug for EventHandler<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The callback is only borrowed while it runs, so formatting the handler from inside its own callback
+        // still works.
+        let set = self
+            .callback
+            .try_borrow()
+            .map_or(true, |callback| callback.is_some());
+
+        f.debug_struct("EventHandler").field("set", &set).finish()
+    }
+}
+
 type ExternalListenerCallback<'bump, T> = bumpalo::boxed::Box<'bump, dyn FnMut(T) + 'bump>;
 
-impl<T> EventHandler<'_, T> {
+impl<'bump, T> EventHandler<'bump, T> {
+    /// Create an event handler that does nothing when called
+    ///
+    /// This is the same as [`EventHandler::default`], but it makes it clear at the call site that the handler is
+    /// intentionally empty.
+    pub fn noop() -> Self {
+        Self::default()
+    }
+
+    /// Create an event handler from a closure, allocating it in `bump`
+    ///
+    /// This is useful for building handlers outside of `rsx!` and components, e.g. in tests or libraries. Inside a
+    /// component, use [`ScopeState::event_handler`] instead, which allocates in the component's arena and runs the
+    /// callback in the component's scope. Handlers created with this function run in the root scope.
+    #[track_caller]
+    pub fn new(bump: &'bump Bump, f: impl FnMut(T) + 'bump) -> Self {
+        let handler: &mut dyn FnMut(T) = bump.alloc(f);
+        let callback = unsafe { BumpBox::from_raw(handler as *mut dyn FnMut(T)) };
+        Self {
+            origin: ScopeId::ROOT,
+            callback: RefCell::new(Some(callback)),
+            once: Cell::new(false),
+            #[cfg(debug_assertions)]
+            created_at: Some(Location::caller()),
+        }
+    }
+
     /// Call this event handler with the appropriate event type
     ///
     /// This borrows the event using a RefCell. Recursively calling a listener will cause a panic.
@@ -165,12 +216,353 @@ impl<T> EventHandler<'_, T> {
                 rt.scope_stack.borrow_mut().pop();
             });
         }
+
+        if self.once.get() {
+            self.release();
+        }
+    }
+
+    /// Make this handler call its callback at most once, and release it after that
+    ///
+    /// This is useful for one-shot callbacks, like the confirm button of a dialog. Any calls after the first one
+    /// don't do anything, like the calls to a handler that was released with [`EventHandler::release`].
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// fn Dialog<'a>(cx: Scope<'a, DialogProps<'a>>) -> Element<'a> {
+    ///     let onconfirm = cx.event_handler(move |_| cx.props.onclose.call(true)).once();
+    ///     render! {
+    ///         button { onclick: move |_| onconfirm.call(()), "Confirm" }
+    ///     }
+    /// }
+    /// ```
+    pub fn once(self) -> Self {
+        self.once.set(true);
+        self
+    }
+
+    /// Create a handler that spawns the future returned by `f` for every event, without waiting for it
+    ///
+    /// The futures are spawned with [`ScopeState::spawn`] on the scope `cx` belongs to, so the
+    /// [`VirtualDom`](crate::VirtualDom) polls them like any other task of that component, and drops them when the
+    /// component is unmounted. They have to be `'static`, so move or clone what they need out of the event and the
+    /// component into an `async move` block.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// fn Save(cx: Scope) -> Element {
+    ///     let onsave = EventHandler::spawning(cx, |draft: String| async move {
+    ///         api::save(draft).await;
+    ///     });
+    ///     render! {
+    ///         Editor { onsave: move |draft| onsave.call(draft) }
+    ///     }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn spawning<F: Future<Output = ()> + 'static>(
+        cx: &'bump ScopeState,
+        mut f: impl FnMut(T) -> F + 'bump,
+    ) -> Self {
+        cx.event_handler(move |event| cx.spawn(f(event)))
+    }
+
+    /// Check if this handler has a callback, e.g. to only render an interactive element when a parent passed one
+    ///
+    /// This borrows the callback using a RefCell, so checking a handler from inside its own callback will cause a panic.
+    pub fn is_set(&self) -> bool {
+        self.callback.borrow().is_some()
     }
 
     /// Forcibly drop the internal handler callback, releasing memory
     ///
     /// This will force any future calls to "call" to not doing anything
+    ///
+    /// The callback is taken out of the handler before it's dropped, so its destructor runs after the
+    /// handler is no longer borrowed and can clean up resources (like subscriptions) it holds.
+    /// Releasing a handler from inside its own callback will cause a panic.
+    pub fn release(&self) {
+        let old = self.callback.take();
+        drop(old);
+    }
+
+    /// Replace the callback of this handler with the callback of `handler`, dropping the old one
+    ///
+    /// Like [`EventHandler::release`], the old callback is dropped only once the new one is in place and the
+    /// handler isn't borrowed anymore, so its destructor can run any cleanup, even if that cleanup calls this
+    /// handler again. Whether the handler is [`EventHandler::once`] is taken from `handler` along with the callback,
+    /// but the handler keeps its original scope, and [`EventHandler::created_at`] still points at where this handler
+    /// was created. Replacing a handler from inside its own callback will cause a panic.
+    pub fn replace(&self, handler: EventHandler<'bump, T>) {
+        self.once.set(handler.once.get());
+        let old = self.callback.replace(handler.callback.into_inner());
+        drop(old);
+    }
+
+    /// The scope of the component that created this handler
+    ///
+    /// The callback runs in this scope, so hooks like `use_context` called from it read that component's context.
+    /// Devtools can look up the component's name with [`VirtualDom::get_scope`](crate::VirtualDom::get_scope).
+    pub fn origin(&self) -> ScopeId {
+        self.origin
+    }
+
+    /// The source location where this handler was created, like the `rsx!` call that passed a closure to a component
+    ///
+    /// This is only tracked in debug builds, so it always returns `None` in release builds.
+    /// It's also `None` for handlers that were never given a callback, like [`EventHandler::noop`].
+    pub fn created_at(&self) -> Option<&'static Location<'static>> {
+        #[cfg(debug_assertions)]
+        {
+            self.created_at
+        }
+
+        #[cfg(not(debug_assertions))]
+        {
+            None
+        }
+    }
+
+    /// Create a new handler for events of type `U`, which converts them with `f` before calling this handler.
+    ///
+    /// This is useful when wrapping a component, to translate its events into the events of the wrapper.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// fn NameField<'a>(cx: Scope<'a, NameFieldProps<'a>>) -> Element<'a> {
+    ///     let onchange = cx.props.onrename.map(cx, |name: String| Rename { name });
+    ///     render! {
+    ///         TextField { onchange: move |value| onchange.call(value) }
+    ///     }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn map<U>(
+        &'bump self,
+        cx: &'bump ScopeState,
+        f: impl Fn(U) -> T + 'bump,
+    ) -> EventHandler<'bump, U> {
+        cx.event_handler(move |event: U| self.call(f(event)))
+    }
+}
+
+/// Like [`EventHandler`], but the callback returns a value to the component calling it.
+///
+/// This lets a child ask its parent to compute something synchronously, like validating an input.
+/// Create one with [`ScopeState::query_handler`]. Props starting with `on` are turned into [`EventHandler`]s by
+/// `rsx!`, so give query props a different name.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// rsx!{
+///     Input { validate: cx.query_handler(|value: String| !value.is_empty()) }
+/// }
+///
+/// #[derive(Props)]
+/// struct InputProps<'a> {
+///     validate: QueryHandler<'a, String, bool>,
+/// }
+///
+/// fn Input<'a>(cx: Scope<'a, InputProps<'a>>) -> Element<'a> {
+///     let valid = use_state(cx, || true);
+///     cx.render(rsx!{
+///         input {
+///             oninput: move |evt| valid.set(cx.props.validate.call(evt.value.clone()).unwrap_or(true)),
+///         }
+///     })
+/// }
+/// ```
+pub struct QueryHandler<'bump, T = (), R = ()> {
+    pub(crate) origin: ScopeId,
+    pub(super) callback: RefCell<Option<ExternalQueryCallback<'bump, T, R>>>,
+}
+
+impl<T, R> Default for QueryHandler<'_, T, R> {
+    fn default() -> Self {
+        Self {
+            origin: ScopeId::ROOT,
+            callback: Default::default(),
+        }
+    }
+}
+
+type ExternalQueryCallback<'bump, T, R> = bumpalo::boxed::Box<'bump, dyn FnMut(T) -> R + 'bump>;
+
+impl<T, R> QueryHandler<'_, T, R> {
+    /// Call this query handler with the appropriate event type, returning `None` if no callback is set
+    ///
+    /// This borrows the event using a RefCell. Recursively calling a handler will cause a panic.
+    pub fn call(&self, event: T) -> Option<R> {
+        let mut callback = self.callback.borrow_mut();
+        let callback = callback.as_mut()?;
+        with_runtime(|rt| {
+            rt.scope_stack.borrow_mut().push(self.origin);
+        });
+        let result = callback(event);
+        with_runtime(|rt| {
+            rt.scope_stack.borrow_mut().pop();
+        });
+        Some(result)
+    }
+
+    /// Forcibly drop the internal handler callback, releasing memory
+    ///
+    /// This will force any future calls to "call" to return `None`
     pub fn release(&self) {
-        self.callback.replace(None);
+        let old = self.callback.take();
+        drop(old);
+    }
+}
+
+/// An event that expects a response, sent with [`EventHandler::request`].
+///
+/// The handler can answer right away, or store the request and answer later, like after the user confirms a dialog.
+/// Dropping the request without answering resolves the requester's future to `None`.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// #[derive(Props)]
+/// struct DeleteButtonProps<'a> {
+///     onconfirm: EventHandler<'a, EventRequest<String, bool>>,
+/// }
+///
+/// fn DeleteButton<'a>(cx: Scope<'a, DeleteButtonProps<'a>>) -> Element<'a> {
+///     render! {
+///         button {
+///             onclick: move |_| {
+///                 let confirmed = cx.props.onconfirm.request("Delete this file?".to_string());
+///                 cx.spawn(async move {
+///                     if confirmed.await == Some(true) {
+///                         // delete the file
+///                     }
+///                 });
+///             },
+///             "Delete"
+///         }
+///     }
+/// }
+///
+/// fn App(cx: Scope) -> Element {
+///     render! {
+///         DeleteButton { onconfirm: move |request: EventRequest<String, bool>| request.respond(true) }
+///     }
+/// }
+/// ```
+pub struct EventRequest<T, R> {
+    event: T,
+    responder: futures_channel::oneshot::Sender<R>,
+}
+
+impl<T, R> EventRequest<T, R> {
+    /// The event the request was made with
+    pub fn event(&self) -> &T {
+        &self.event
+    }
+
+    /// Answer the request, resolving the requester's future to `Some(response)`
+    ///
+    /// If the requester already dropped its future, the response is dropped too.
+    pub fn respond(self, response: R) {
+        let _ = self.responder.send(response);
+    }
+}
+
+impl<'bump, T, R: 'static> EventHandler<'bump, EventRequest<T, R>> {
+    /// Call this handler with a request, returning a future that resolves to the handler's response
+    ///
+    /// The future resolves to `None` if the handler drops the request without responding, which includes the case
+    /// where no callback is set. It doesn't borrow the handler, so it can be passed to `cx.spawn`.
+    pub fn request(&self, event: T) -> impl Future<Output = Option<R>> + 'static {
+        let (responder, response) = futures_channel::oneshot::channel();
+        self.call(EventRequest { event, responder });
+        async move { response.await.ok() }
+    }
+}
+
+impl<'bump, E: 'bump, C: Clone + 'bump> EventHandler<'bump, (E, C)> {
+    /// Create a new handler that pairs every event with a clone of `context` before passing it to this handler.
+    ///
+    /// This is useful when a handler needs ambient data (like a theme or locale) that is only known when the
+    /// handler is handed to a child, rather than when it is created.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// #[derive(Props)]
+    /// struct LocalizedProps<'a> {
+    ///     onsubmit: EventHandler<'a, (String, Locale)>,
+    /// }
+    ///
+    /// fn Localized<'a>(cx: Scope<'a, LocalizedProps<'a>>) -> Element<'a> {
+    ///     let locale = *use_shared_state::<Locale>(cx).unwrap().read();
+    ///     render! {
+    ///         Form { onsubmit: cx.props.onsubmit.with_context(cx, locale) }
+    ///     }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn with_context(&'bump self, cx: &'bump ScopeState, context: C) -> EventHandler<'bump, E> {
+        cx.event_handler(move |event| self.call((event, context.clone())))
+    }
+}
+
+impl<'bump, T: Clone + 'bump> EventHandler<'bump, T> {
+    /// Create a new handler that calls this handler and then `other`, with the same event.
+    ///
+    /// The event is cloned for this handler and moved into `other`.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// fn Field<'a>(cx: Scope<'a, FieldProps<'a>>) -> Element<'a> {
+    ///     let onchange = cx.props.onchange.chain(cx, &cx.props.onvalidate);
+    ///     render! {
+    ///         input { oninput: move |evt| onchange.call(evt.value.clone()) }
+    ///     }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn chain(
+        &'bump self,
+        cx: &'bump ScopeState,
+        other: &'bump EventHandler<'bump, T>,
+    ) -> EventHandler<'bump, T> {
+        cx.event_handler(move |event: T| {
+            self.call(event.clone());
+            other.call(event);
+        })
+    }
+}
+
+impl<'bump, T: PartialEq + Clone + 'bump> EventHandler<'bump, T> {
+    /// Create a new handler that only calls this handler when the event differs from the previous one.
+    ///
+    /// The last event is cloned and stored inside the new handler's closure, so it lives as long as the new handler
+    /// and is dropped together with it. Prefer small event types, or map them to a cheap key first.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// fn Search<'a>(cx: Scope<'a, SearchProps<'a>>) -> Element<'a> {
+    ///     let onquery = cx.props.onquery.distinct_until_changed(cx);
+    ///     render! {
+    ///         input { oninput: move |evt| onquery.call(evt.value.clone()) }
+    ///     }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn distinct_until_changed(&'bump self, cx: &'bump ScopeState) -> EventHandler<'bump, T> {
+        let mut last = None;
+        cx.event_handler(move |event: T| {
+            if last.as_ref() != Some(&event) {
+                last = Some(event.clone());
+                self.call(event);
+            }
+        })
     }
 }
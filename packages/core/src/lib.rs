@@ -15,6 +15,8 @@ mod fragment;
 mod lazynodes;
 mod mutations;
 mod nodes;
+#[cfg(feature = "preview")]
+mod preview;
 mod properties;
 mod runtime;
 mod scheduler;
@@ -77,12 +79,16 @@ pub(crate) mod innerlude {
 
 pub use crate::innerlude::{
     fc_to_builder, vdom_is_rendering, AnyValue, Attribute, AttributeValue, BorrowedAttributeValue,
-    CapturedError, Component, DynamicNode, Element, ElementId, Event, Fragment, IntoDynNode,
-    LazyNodes, Mutation, Mutations, Properties, RenderReturn, Scope, ScopeId, ScopeState, Scoped,
-    TaskId, Template, TemplateAttribute, TemplateNode, VComponent, VNode, VPlaceholder, VText,
-    VirtualDom,
+    CapturedError, Component, DynProps, DynamicNode, Element, ElementId, Event, EventHandler,
+    Fragment, FromReactive, IntoDynNode, IntoReactive, LazyNodes, Mutation, Mutations, Properties,
+    PropsError, RenderReturn, Scope, ScopeId, ScopeState, Scoped, TaskId, Template,
+    TemplateAttribute, TemplateNode, VComponent, VNode, VPlaceholder, VText, VirtualDom,
+    BOOL_ATTRS,
 };
 
+#[cfg(feature = "preview")]
+pub use crate::preview::{previews, ComponentPreview};
+
 /// The purpose of this module is to alleviate imports of many common types
 ///
 /// This includes types like [`Scope`], [`Element`], and [`Component`].
@@ -91,9 +97,10 @@ pub mod prelude {
         consume_context, consume_context_from_scope, current_scope_id, fc_to_builder, has_context,
         provide_context, provide_context_to_scope, provide_root_context, push_future,
         remove_future, schedule_update_any, spawn, spawn_forever, suspend, throw, AnyValue,
-        Component, Element, Event, EventHandler, Fragment, IntoAttributeValue, IntoDynNode,
-        LazyNodes, Properties, Runtime, RuntimeGuard, Scope, ScopeId, ScopeState, Scoped, TaskId,
-        Template, TemplateAttribute, TemplateNode, Throw, VNode, VirtualDom,
+        Component, Element, Event, EventHandler, EventRequest, Fragment, IntoAttributeValue,
+        IntoDynNode, LazyNodes, Properties, QueryHandler, Runtime, RuntimeGuard, Scope, ScopeId,
+        ScopeState, Scoped, TaskId, Template, TemplateAttribute, TemplateNode, Throw, VNode,
+        VirtualDom,
     };
 }
 
@@ -101,4 +108,6 @@ pub mod exports {
     //! Important dependencies that are used by the rest of the library
     //! Feel free to just add the dependencies in your own Crates.toml
     pub use bumpalo;
+    #[cfg(feature = "preview")]
+    pub use inventory;
 }
@@ -92,6 +92,48 @@ impl<'a> VNode<'a> {
             }
         }
     }
+
+    /// Find the first dynamic attribute with the given name and no namespace
+    ///
+    /// Static attributes are part of the [`Template`], so only [`VNode::dynamic_attrs`] are searched. These belong to
+    /// every element in the template, not just the root, and are searched in order.
+    pub fn attr(&self, name: &str) -> Option<&'a Attribute<'a>> {
+        self.attr_ns(name, None)
+    }
+
+    /// Find the first dynamic attribute with the given name and namespace, like `("color", Some("style"))`
+    ///
+    /// See [`VNode::attr`] for which attributes are searched.
+    pub fn attr_ns(&self, name: &str, ns: Option<&str>) -> Option<&'a Attribute<'a>> {
+        self.dynamic_attrs
+            .iter()
+            .find(|attr| attr.name == name && attr.namespace == ns)
+    }
+
+    /// Find the value of the first dynamic attribute with the given name and no namespace
+    ///
+    /// See [`VNode::attr`] for which attributes are searched.
+    pub fn attr_value(&self, name: &str) -> Option<&'a AttributeValue<'a>> {
+        self.attr(name).map(|attr| &attr.value)
+    }
+
+    /// Find the first listener for the given event, like `"click"` for an `onclick` listener
+    ///
+    /// Like [`VNode::attr`], this searches the dynamic attributes of every element in the template. An element
+    /// isn't expected to have more than one listener for the same event, but if it does, the first one is returned.
+    pub fn listener(&self, event: &str) -> Option<&'a Attribute<'a>> {
+        self.dynamic_attrs.iter().find(|attr| {
+            matches!(attr.value, AttributeValue::Listener(_))
+                && attr.name.strip_prefix("on") == Some(event)
+        })
+    }
+
+    /// Check if there's a listener for the given event, like `"click"` for an `onclick` listener
+    ///
+    /// See [`VNode::listener`] for which listeners are searched.
+    pub fn has_listener(&self, event: &str) -> bool {
+        self.listener(event).is_some()
+    }
 }
 
 /// A static layout of a UI tree that describes a set of dynamic and static nodes.
@@ -204,6 +246,27 @@ impl<'a> Template<'a> {
     }
 }
 
+impl<'a> TemplateNode<'a> {
+    /// Is this node's whole subtree known at compile time?
+    ///
+    /// A static subtree has no dynamic attributes (listeners included) and no dynamic children, so it never
+    /// changes between renders and renderers can skip diffing it entirely.
+    pub fn is_static(&self) -> bool {
+        match self {
+            TemplateNode::Element {
+                attrs, children, ..
+            } => {
+                attrs
+                    .iter()
+                    .all(|attr| matches!(attr, TemplateAttribute::Static { .. }))
+                    && children.iter().all(TemplateNode::is_static)
+            }
+            TemplateNode::Text { .. } => true,
+            TemplateNode::Dynamic { .. } | TemplateNode::DynamicText { .. } => false,
+        }
+    }
+}
+
 /// A statically known node in a layout.
 ///
 /// This can be created at compile time, saving the VirtualDom time when diffing the tree
@@ -414,6 +477,37 @@ pub enum TemplateAttribute<'a> {
     },
 }
 
+/// The HTML attributes that are set by being present, like `disabled`, rather than by their value
+pub const BOOL_ATTRS: &[&str] = &[
+    "allowfullscreen",
+    "allowpaymentrequest",
+    "async",
+    "autofocus",
+    "autoplay",
+    "checked",
+    "controls",
+    "default",
+    "defer",
+    "disabled",
+    "formnovalidate",
+    "hidden",
+    "ismap",
+    "itemscope",
+    "loop",
+    "multiple",
+    "muted",
+    "nomodule",
+    "novalidate",
+    "open",
+    "playsinline",
+    "readonly",
+    "required",
+    "reversed",
+    "selected",
+    "truespeed",
+    "webkitdirectory",
+];
+
 /// An attribute on a DOM node, such as `id="my-thing"` or `href="https://example.com"`
 #[derive(Debug)]
 pub struct Attribute<'a> {
@@ -431,6 +525,12 @@ pub struct Attribute<'a> {
     /// An indication of we should always try and set the attribute. Used in controlled components to ensure changes are propagated
     pub volatile: bool,
 
+    /// Whether this is an HTML boolean attribute, like `disabled` or `checked`, which is set by being present.
+    ///
+    /// A boolean attribute with a falsey value (`false`, `0`, `""`, `"false"` or `None`) is left out of the DOM entirely,
+    /// instead of being rendered as `disabled="false"`. Attributes created by `rsx!` are tagged with [`BOOL_ATTRS`].
+    pub(crate) boolean: bool,
+
     /// The element in the DOM that this attribute belongs to
     pub(crate) mounted_element: Cell<ElementId>,
 }
@@ -448,14 +548,50 @@ impl<'a> Attribute<'a> {
             value,
             namespace,
             volatile,
+            boolean: false,
             mounted_element: Cell::new(ElementId::default()),
         }
     }
 
+    /// Whether this attribute shouldn't be in the DOM at all: either its value is `None`, like an `Option`
+    /// attribute that isn't set, or it's a boolean attribute with a falsey value
+    pub(crate) fn is_omitted(&self) -> bool {
+        match &self.value {
+            AttributeValue::None => true,
+            AttributeValue::Text(value) => {
+                self.boolean
+                    && (value.is_empty() || *value == "0" || value.eq_ignore_ascii_case("false"))
+            }
+            AttributeValue::Float(value) => self.boolean && *value == 0.0,
+            AttributeValue::Int(value) => self.boolean && *value == 0,
+            AttributeValue::Bool(value) => self.boolean && !*value,
+            AttributeValue::Listener(_) | AttributeValue::Any(_) => false,
+        }
+    }
+
+    /// Whether this is an HTML boolean attribute, like `disabled` or `checked`, which is set by being present
+    ///
+    /// A boolean attribute with a falsey value (`false`, `0`, `""`, `"false"` or `None`) is left out of the DOM
+    /// entirely. Attributes created by `rsx!` are tagged by their name, but ones built with [`Attribute::new`] aren't.
+    pub fn is_boolean(&self) -> bool {
+        self.boolean
+    }
+
     /// Get the element that this attribute is mounted to
     pub fn mounted_element(&self) -> ElementId {
         self.mounted_element.get()
     }
+
+    /// Check if this is a listener for the given event, like `"click"` for an `onclick` listener, that is mounted to
+    /// the given element
+    ///
+    /// Renderers that delegate events to a single root listener can use this to find the listeners that an incoming
+    /// event should reach.
+    pub fn matches_listener(&self, event: &str, node: ElementId) -> bool {
+        matches!(self.value, AttributeValue::Listener(_))
+            && self.name.strip_prefix("on") == Some(event)
+            && self.mounted_element.get() == node
+    }
 }
 
 /// Any of the built-in values that the Dioxus VirtualDom supports as dynamic attributes on elements
@@ -824,6 +960,8 @@ impl<'a> IntoAttributeValue<'a> for BumpBox<'a, dyn AnyValue> {
     }
 }
 
+/// `None` converts to [`AttributeValue::None`], which leaves the attribute out of the DOM, or removes it if it was set, so
+/// `title: if show_title { Some("...") } else { None }` only sets the attribute when there is a value.
 impl<'a, T: IntoAttributeValue<'a>> IntoAttributeValue<'a> for Option<T> {
     fn into_value(self, bump: &'a Bump) -> AttributeValue<'a> {
         match self {
@@ -0,0 +1,30 @@
+use crate::innerlude::Component;
+
+/// A component rendered with placeholder props, registered by `#[component(preview)]`.
+///
+/// Component galleries can list every registered preview with [`previews`] and render them with a
+/// [`VirtualDom`](crate::VirtualDom).
+#[derive(Clone, Copy)]
+pub struct ComponentPreview {
+    /// The name of the previewed component.
+    pub name: &'static str,
+    /// A propless component that renders the previewed component with placeholder props.
+    pub render: Component,
+}
+
+inventory::collect!(ComponentPreview);
+
+/// Iterate over every [`ComponentPreview`] registered in the binary, in no particular order.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// for preview in dioxus_core::previews() {
+///     let mut dom = VirtualDom::new(preview.render);
+///     let _ = dom.rebuild();
+///     println!("{}: {}", preview.name, dioxus_ssr::render(&dom));
+/// }
+/// ```
+pub fn previews() -> impl Iterator<Item = &'static ComponentPreview> {
+    inventory::iter::<ComponentPreview>.into_iter()
+}
@@ -32,6 +32,15 @@ use crate::innerlude::*;
 ///     data: &'a str
 /// }
 /// ```
+// The build script only sets this on compilers that understand the `diagnostic` attribute namespace.
+#[cfg_attr(
+    dioxus_diagnostic_namespace,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` can't be used as component props",
+        label = "this type doesn't implement `Properties`",
+        note = "add `#[derive(Props)]` to `{Self}`, or use `#[component]` to generate the props struct from the component's arguments"
+    )
+)]
 pub trait Properties: Sized {
     /// The type of the builder for this component.
     /// Used to create "in-progress" versions of the props.
@@ -68,6 +77,85 @@ impl EmptyBuilder {
     pub fn build(self) {}
 }
 
+/// The error returned by a props builder's `try_build` method when one or more fields are rejected by their
+/// `#[props(validate = ...)]` function.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// fn not_empty(value: &String) -> Result<(), &'static str> {
+///     if value.is_empty() { Err("must not be empty") } else { Ok(()) }
+/// }
+///
+/// #[derive(Props, PartialEq)]
+/// struct MyProps {
+///     #[props(validate = not_empty)]
+///     name: String,
+/// }
+///
+/// let err = MyProps::builder().name(String::new()).try_build().unwrap_err();
+/// assert_eq!(err.fields, vec![("name", "must not be empty".to_string())]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropsError {
+    /// The name of the props struct that failed to build
+    pub props: &'static str,
+
+    /// Every rejected field, in declaration order, along with the message its validator returned
+    pub fields: Vec<(&'static str, String)>,
+}
+
+impl std::fmt::Display for PropsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid {}", self.props)?;
+        for (idx, (field, message)) in self.fields.iter().enumerate() {
+            let separator = if idx == 0 { ": " } else { ", " };
+            write!(f, "{separator}`{field}` {message}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for PropsError {}
+
+/// Lets tools inspect the props of any component without knowing their type, like devtools listing the props of
+/// every component in the tree.
+///
+/// Props structs generated by `#[component(reflect)]` implement it automatically.
+pub trait DynProps {
+    /// The names of the props, in declaration order
+    fn field_names(&self) -> &'static [&'static str];
+
+    /// Erase the type of the props
+    fn as_dyn_props(&self) -> &dyn DynProps
+    where
+        Self: Sized,
+    {
+        self
+    }
+}
+
+/// Builds a `#[props(reactive)]` prop from the value passed to its setter, like a reactive container from either a
+/// plain value or another container
+///
+/// This is implemented by the type of the prop. Setters take an [`IntoReactive`] instead, like [`From`] and [`Into`].
+pub trait FromReactive<V>: Sized {
+    /// Convert the value passed to the setter into the prop
+    fn from_reactive(value: V) -> Self;
+}
+
+/// The counterpart of [`FromReactive`], which the setters of `#[props(reactive)]` props take
+pub trait IntoReactive<R> {
+    /// Convert this value into the prop
+    fn into_reactive(self) -> R;
+}
+
+impl<V, R: FromReactive<V>> IntoReactive<R> for V {
+    fn into_reactive(self) -> R {
+        R::from_reactive(self)
+    }
+}
+
 /// This utility function launches the builder method so rsx! and html! macros can use the typed-builder pattern
 /// to initialize a component's props.
 pub fn fc_to_builder<'a, T: Properties + 'a>(_: fn(Scope<'a, T>) -> Element<'a>) -> T::Builder {
@@ -13,6 +13,7 @@ impl VirtualDom {
         &mut self,
         props: Box<dyn AnyProps<'static>>,
         name: &'static str,
+        key: Option<&str>,
     ) -> &ScopeState {
         let parent_id = self.runtime.current_scope_id();
         let height = parent_id
@@ -36,6 +37,8 @@ impl VirtualDom {
 
             borrowed_props: Default::default(),
             attributes_to_drop_before_render: Default::default(),
+
+            key: key.map(Into::into),
         }));
 
         let context =
@@ -3,14 +3,15 @@ use crate::{
     any_props::VProps,
     bump_frame::BumpFrame,
     innerlude::ErrorBoundary,
-    innerlude::{DynamicNode, EventHandler, VComponent, VText},
+    innerlude::{DynamicNode, EventHandler, QueryHandler, VComponent, VText},
     lazynodes::LazyNodes,
-    nodes::{IntoAttributeValue, IntoDynNode, RenderReturn},
+    nodes::{IntoAttributeValue, IntoDynNode, RenderReturn, BOOL_ATTRS},
     runtime::Runtime,
     scope_context::ScopeContext,
     AnyValue, Attribute, AttributeValue, Element, Event, Properties, TaskId,
 };
 use bumpalo::{boxed::Box as BumpBox, Bump};
+use futures_util::{pin_mut, task::ArcWake};
 use std::{
     any::Any,
     cell::{Cell, Ref, RefCell, UnsafeCell},
@@ -18,6 +19,7 @@ use std::{
     future::Future,
     rc::Rc,
     sync::Arc,
+    task::{Context, Poll},
 };
 
 /// A wrapper around the [`Scoped`] object that contains a reference to the [`ScopeState`] and properties for a given
@@ -97,6 +99,8 @@ pub struct ScopeState {
     pub(crate) attributes_to_drop_before_render: RefCell<Vec<*const Attribute<'static>>>,
 
     pub(crate) props: Option<Box<dyn AnyProps<'static>>>,
+
+    pub(crate) key: Option<Box<str>>,
 }
 
 impl Drop for ScopeState {
@@ -131,6 +135,13 @@ impl<'src> ScopeState {
         self.context().name
     }
 
+    /// Get the key this component was created with, if it's the root of a keyed template, like `Item { key: "{id}" }`
+    ///
+    /// Keyed diffing only reuses a component's scope for the same key, so the key never changes afterwards.
+    pub fn key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+
     /// Get the current render since the inception of this component
     ///
     /// This can be used as a helpful diagnostic when debugging hooks/renders, etc
@@ -399,6 +410,33 @@ impl<'src> ScopeState {
         })
     }
 
+    /// Allocate some attribute text inside the [`ScopeState`] from [`Arguments`], sharing the allocation with
+    /// identical text from the same render
+    ///
+    /// Repeated values, like the same class on every row of a list, only take up space in the [`Bump`] once, at the
+    /// cost of a lookup for every value. Formatted attributes in `rsx!` use [`ScopeState::raw_text`], so interning is
+    /// opt-in per attribute, like `class: cx.intern_text(format_args!("todo {state}"))`.
+    pub fn intern_text(&'src self, args: Arguments) -> &'src str {
+        if let Some(text) = args.as_str() {
+            return text;
+        }
+
+        let frame = self.previous_frame();
+        let mut buffer = frame.text_buffer.borrow_mut();
+        buffer.clear();
+        std::fmt::Write::write_fmt(&mut *buffer, args).unwrap();
+
+        let mut interned = frame.interned_text.borrow_mut();
+        if let Some(&text) = interned.get(buffer.as_str()) {
+            return text;
+        }
+
+        let text: &'src str = self.bump().alloc_str(&buffer);
+        // Safety: the interned text is cleared before the bump is reset, so it never outlives its allocation
+        interned.insert(unsafe { std::mem::transmute::<&str, &'static str>(text) });
+        text
+    }
+
     /// Convert any item that implements [`IntoDynNode`] into a [`DynamicNode`] using the internal [`Bump`] allocator
     pub fn make_node<'c, I>(&'src self, into: impl IntoDynNode<'src, I> + 'c) -> DynamicNode {
         into.into_vnode(self)
@@ -408,6 +446,8 @@ impl<'src> ScopeState {
     ///
     /// "Volatile" referes to whether or not Dioxus should always override the value. This helps prevent the UI in
     /// some renderers stay in sync with the VirtualDom's understanding of the world
+    ///
+    /// Attributes without a namespace that are listed in [`BOOL_ATTRS`] are marked as [`Attribute::is_boolean`].
     pub fn attr(
         &'src self,
         name: &'static str,
@@ -419,6 +459,7 @@ impl<'src> ScopeState {
             name,
             namespace,
             volatile,
+            boolean: namespace.is_none() && BOOL_ATTRS.contains(&name),
             mounted_element: Default::default(),
             value: value.into_value(self.bump()),
         }
@@ -466,11 +507,24 @@ impl<'src> ScopeState {
     }
 
     /// Create a new [`EventHandler`] from an [`FnMut`]
+    ///
+    /// In debug builds, the caller's location is stored in the handler, see [`EventHandler::created_at`].
+    #[track_caller]
     pub fn event_handler<T>(&'src self, f: impl FnMut(T) + 'src) -> EventHandler<'src, T> {
-        let handler: &mut dyn FnMut(T) = self.bump().alloc(f);
-        let caller = unsafe { BumpBox::from_raw(handler as *mut dyn FnMut(T)) };
+        let mut handler = EventHandler::new(self.bump(), f);
+        handler.origin = self.context().id;
+        handler
+    }
+
+    /// Create a new [`QueryHandler`] from an [`FnMut`] that returns a value
+    pub fn query_handler<T, R>(
+        &'src self,
+        f: impl FnMut(T) -> R + 'src,
+    ) -> QueryHandler<'src, T, R> {
+        let handler: &mut dyn FnMut(T) -> R = self.bump().alloc(f);
+        let caller = unsafe { BumpBox::from_raw(handler as *mut dyn FnMut(T) -> R) };
         let callback = RefCell::new(Some(caller));
-        EventHandler {
+        QueryHandler {
             callback,
             origin: self.context().id,
         }
@@ -532,6 +586,28 @@ impl<'src> ScopeState {
         None
     }
 
+    /// Render this component from a future, like the body of an `async` component.
+    ///
+    /// The future is polled once, right away. If it's ready, its element is returned. Otherwise this component is
+    /// suspended, and rendered again when the future is woken.
+    ///
+    /// The future borrows the scope, so it can't be kept between renders, and every render polls a new one.
+    /// Await things that keep their progress outside of the future, like a value stored in a hook,
+    /// or the work starts over on every render.
+    pub fn render_async(&'src self, future: impl Future<Output = Element<'src>>) -> Element<'src> {
+        pin_mut!(future);
+
+        let waker = futures_util::task::waker(Arc::new(ScopeWaker(self.schedule_update())));
+
+        match future.poll(&mut Context::from_waker(&waker)) {
+            Poll::Ready(element) => element,
+            Poll::Pending => {
+                self.suspend();
+                None
+            }
+        }
+    }
+
     /// Store a value between renders. The foundational hook for all other hooks.
     ///
     /// Accepts an `initializer` closure, which is run on the first use of the hook (typically the initial render). The return value of this closure is stored for the lifetime of the component, and a mutable reference to it is provided on every render as the return value of `use_hook`.
@@ -575,3 +651,12 @@ impl<'src> ScopeState {
             )
     }
 }
+
+/// Wakes a future rendered with [`ScopeState::render_async`] by scheduling a render of its scope.
+struct ScopeWaker(Arc<dyn Fn() + Send + Sync>);
+
+impl ArcWake for ScopeWaker {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        (arc_self.0)()
+    }
+}
@@ -270,6 +270,7 @@ impl VirtualDom {
         let root = dom.new_scope(
             Box::new(VProps::new(root, |_, _| unreachable!(), root_props)),
             "app",
+            None,
         );
 
         // Unlike react, we provide a default error boundary that just renders the error as a string
@@ -97,3 +97,41 @@ fn attrs_cycle() {
         ]
     );
 }
+
+#[test]
+fn none_attrs_are_left_out_and_removed() {
+    let mut dom = VirtualDom::new(|cx| {
+        let title = (cx.generation() % 2 == 1).then_some("tooltip");
+        cx.render(rsx! { div { title: title } })
+    });
+
+    assert_eq!(
+        dom.rebuild().santize().edits,
+        [
+            LoadTemplate { name: "template", index: 0, id: ElementId(1) },
+            AppendChildren { m: 1, id: ElementId(0) },
+        ]
+    );
+
+    dom.mark_dirty(ScopeId::ROOT);
+    assert_eq!(
+        dom.render_immediate().santize().edits,
+        [SetAttribute {
+            name: "title",
+            value: BorrowedAttributeValue::Text("tooltip"),
+            id: ElementId(1),
+            ns: None
+        }]
+    );
+
+    dom.mark_dirty(ScopeId::ROOT);
+    assert_eq!(
+        dom.render_immediate().santize().edits,
+        [SetAttribute {
+            name: "title",
+            value: BorrowedAttributeValue::None,
+            id: ElementId(1),
+            ns: None
+        }]
+    );
+}
@@ -0,0 +1,116 @@
+//! Tests for looking up the dynamic attributes and listeners of a [`VNode`] by name.
+
+use dioxus::prelude::*;
+use dioxus_core::{AttributeValue, ElementId, RenderReturn};
+
+#[test]
+fn attrs_are_looked_up_by_name_and_namespace() {
+    fn app(cx: Scope) -> Element {
+        let size = 12;
+        let node = render! {
+            div { class: "size-{size}", font_size: "{size}px",
+                span { id: "label-{size}" }
+            }
+        }
+        .unwrap();
+
+        assert_eq!(
+            node.attr_value("class"),
+            Some(&AttributeValue::Text("size-12"))
+        );
+        // Attributes of nested elements are found too.
+        assert_eq!(
+            node.attr_value("id"),
+            Some(&AttributeValue::Text("label-12"))
+        );
+
+        // `font_size` is a style attribute, so it's only found with its namespace.
+        assert!(node.attr("font-size").is_none());
+        let font_size = node.attr_ns("font-size", Some("style")).unwrap();
+        assert_eq!(font_size.value, AttributeValue::Text("12px"));
+
+        assert!(node.attr("title").is_none());
+
+        render! { "done" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+}
+
+#[test]
+fn listeners_are_looked_up_by_event_name() {
+    fn app(cx: Scope) -> Element {
+        let node = render! {
+            button { onclick: |_| {}, onkeydown: |_| {}, "save" }
+        }
+        .unwrap();
+
+        assert!(node.has_listener("click"));
+        assert!(node.has_listener("keydown"));
+        assert!(!node.has_listener("input"));
+        // The `on` prefix is part of the attribute name, not the event name.
+        assert!(!node.has_listener("onclick"));
+
+        let onclick = node.listener("click").unwrap();
+        assert_eq!(onclick.name, "onclick");
+
+        render! { "done" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+}
+
+#[test]
+fn listeners_match_their_event_and_element() {
+    let mut dom = VirtualDom::new(|cx| {
+        let kind = "primary";
+        render! { button { onclick: |_| {}, class: "btn-{kind}", "save" } }
+    });
+    _ = dom.rebuild();
+
+    let RenderReturn::Ready(node) = dom.base_scope().root_node() else {
+        unreachable!()
+    };
+    let onclick = node.listener("click").unwrap();
+    let id = onclick.mounted_element();
+    assert!(onclick.matches_listener("click", id));
+    assert!(!onclick.matches_listener("keydown", id));
+    assert!(!onclick.matches_listener("onclick", id));
+    assert!(!onclick.matches_listener("click", ElementId(id.0 + 1)));
+
+    // Other attributes are never listeners, even on the same element.
+    let class = node.attr("class").unwrap();
+    assert!(!class.matches_listener("class", id));
+}
+
+#[test]
+fn repeated_attr_text_is_allocated_once() {
+    fn app(cx: Scope) -> Element {
+        let color = "red";
+        let node = render! {
+            div { class: cx.intern_text(format_args!("text-{color}")) }
+            div { class: cx.intern_text(format_args!("text-{color}")) }
+            div { class: cx.intern_text(format_args!("bg-{color}")) }
+        }
+        .unwrap();
+
+        let texts: Vec<&str> = node
+            .dynamic_attrs
+            .iter()
+            .map(|attr| match &attr.value {
+                AttributeValue::Text(text) => *text,
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(texts, ["text-red", "text-red", "bg-red"]);
+        assert!(std::ptr::eq(texts[0], texts[1]));
+        assert!(!std::ptr::eq(texts[0], texts[2]));
+
+        render! { "done" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+}
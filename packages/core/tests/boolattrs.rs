@@ -1,23 +1,54 @@
 use bumpalo::Bump;
 use dioxus::core::{ElementId, Mutation::*};
 use dioxus::prelude::*;
+use dioxus_core::BorrowedAttributeValue;
 
 #[test]
 fn bool_test() {
     let mut app = VirtualDom::new(|cx| cx.render(rsx!(div { hidden: false })));
+
+    // A falsey boolean attribute is left out entirely
+    assert_eq!(
+        app.rebuild().santize().edits,
+        [
+            LoadTemplate { name: "template", index: 0, id: ElementId(1) },
+            AppendChildren { m: 1, id: ElementId(0) },
+        ]
+    );
+}
+
+#[test]
+fn bool_attrs_are_removed_when_falsey() {
+    let mut app = VirtualDom::new(|cx| cx.render(rsx!(div { hidden: cx.generation() % 2 == 1 })));
     let bump = Bump::new();
 
     assert_eq!(
         app.rebuild().santize().edits,
         [
             LoadTemplate { name: "template", index: 0, id: ElementId(1) },
-            SetAttribute {
-                name: "hidden",
-                value: (&*bump.alloc(false.into_value(&bump))).into(),
-                id: ElementId(1,),
-                ns: None
-            },
             AppendChildren { m: 1, id: ElementId(0) },
         ]
     );
+
+    app.mark_dirty(ScopeId::ROOT);
+    assert_eq!(
+        app.render_immediate().santize().edits,
+        [SetAttribute {
+            name: "hidden",
+            value: (&*bump.alloc(true.into_value(&bump))).into(),
+            id: ElementId(1,),
+            ns: None
+        }]
+    );
+
+    app.mark_dirty(ScopeId::ROOT);
+    assert_eq!(
+        app.render_immediate().santize().edits,
+        [SetAttribute {
+            name: "hidden",
+            value: BorrowedAttributeValue::None,
+            id: ElementId(1,),
+            ns: None
+        }]
+    );
 }
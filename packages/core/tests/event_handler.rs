@@ -0,0 +1,358 @@
+//! Tests for the [`EventHandler`] combinators.
+
+use dioxus::prelude::*;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+#[test]
+fn with_context_pairs_events_with_context() {
+    fn app(cx: Scope) -> Element {
+        let seen: &RefCell<Vec<(u32, &str)>> = cx.use_hook(|| RefCell::new(Vec::new()));
+        let inner = cx.bump().alloc(
+            cx.event_handler(move |event: (u32, &'static str)| seen.borrow_mut().push(event)),
+        );
+
+        let handler = inner.with_context(cx, "en-US");
+        handler.call(1);
+        handler.call(2);
+
+        assert_eq!(*seen.borrow(), [(1, "en-US"), (2, "en-US")]);
+
+        render! { "done" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+}
+
+#[test]
+fn noop_does_nothing() {
+    let handler = EventHandler::<u32>::noop();
+    handler.call(1);
+    handler.call(2);
+}
+
+#[test]
+fn distinct_until_changed_skips_repeated_events() {
+    fn app(cx: Scope) -> Element {
+        let seen: &RefCell<Vec<u32>> = cx.use_hook(|| RefCell::new(Vec::new()));
+        let inner = cx
+            .bump()
+            .alloc(cx.event_handler(move |event: u32| seen.borrow_mut().push(event)));
+
+        let handler = inner.distinct_until_changed(cx);
+        for event in [1, 1, 2, 2, 2, 1] {
+            handler.call(event);
+        }
+
+        assert_eq!(*seen.borrow(), [1, 2, 1]);
+
+        render! { "done" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+}
+
+/// Sets its flag when dropped, like a subscription unsubscribing.
+struct Subscription(Rc<Cell<bool>>);
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        self.0.set(true);
+    }
+}
+
+#[test]
+fn replace_drops_the_old_callback() {
+    fn app(cx: Scope) -> Element {
+        let unsubscribed = cx.use_hook(|| Rc::new(Cell::new(false)));
+        let seen: &RefCell<Vec<&str>> = cx.use_hook(|| RefCell::new(Vec::new()));
+
+        let subscription = Subscription(unsubscribed.clone());
+        let handler = cx.event_handler(move |_: ()| {
+            let _ = &subscription;
+            seen.borrow_mut().push("old");
+        });
+        handler.call(());
+        assert!(!unsubscribed.get());
+
+        handler.replace(cx.event_handler(move |_: ()| seen.borrow_mut().push("new")));
+        assert!(unsubscribed.get());
+
+        handler.call(());
+        assert_eq!(*seen.borrow(), ["old", "new"]);
+
+        handler.release();
+        handler.call(());
+        assert_eq!(*seen.borrow(), ["old", "new"]);
+
+        render! { "done" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+}
+
+#[test]
+fn replace_takes_the_once_flag_of_the_new_handler() {
+    fn app(cx: Scope) -> Element {
+        let calls: &Cell<u32> = cx.use_hook(|| Cell::new(0));
+
+        // A `once` handler swapped into a regular one stops after its first call.
+        let handler = cx.event_handler(|_: ()| {});
+        handler.replace(
+            cx.event_handler(move |_: ()| calls.set(calls.get() + 1))
+                .once(),
+        );
+        handler.call(());
+        handler.call(());
+        assert_eq!(calls.get(), 1);
+
+        // A regular handler swapped into a `once` one keeps firing.
+        let handler = cx.event_handler(|_: ()| {}).once();
+        handler.replace(cx.event_handler(move |_: ()| calls.set(calls.get() + 1)));
+        handler.call(());
+        handler.call(());
+        assert_eq!(calls.get(), 3);
+
+        render! { "done" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+}
+
+#[test]
+fn query_handler_returns_the_callback_result() {
+    fn app(cx: Scope) -> Element {
+        let validate = cx.query_handler(|value: &str| !value.is_empty());
+        assert_eq!(validate.call("bob"), Some(true));
+        assert_eq!(validate.call(""), Some(false));
+
+        validate.release();
+        assert_eq!(validate.call("bob"), None);
+
+        let unset = QueryHandler::<&str, bool>::default();
+        assert_eq!(unset.call("bob"), None);
+
+        render! { "done" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+}
+
+#[test]
+fn chain_calls_both_handlers_in_order() {
+    fn app(cx: Scope) -> Element {
+        let seen: &RefCell<Vec<(&str, u32)>> = cx.use_hook(|| RefCell::new(Vec::new()));
+        let first = cx
+            .bump()
+            .alloc(cx.event_handler(move |event: u32| seen.borrow_mut().push(("first", event))));
+        let second = cx
+            .bump()
+            .alloc(cx.event_handler(move |event: u32| seen.borrow_mut().push(("second", event))));
+
+        let handler = first.chain(cx, second);
+        handler.call(1);
+        handler.call(2);
+
+        assert_eq!(
+            *seen.borrow(),
+            [("first", 1), ("second", 1), ("first", 2), ("second", 2)]
+        );
+
+        render! { "done" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+}
+
+#[derive(Debug, PartialEq)]
+struct Rename {
+    name: String,
+}
+
+#[test]
+fn map_converts_events_before_calling_the_handler() {
+    fn app(cx: Scope) -> Element {
+        let seen: &RefCell<Vec<Rename>> = cx.use_hook(|| RefCell::new(Vec::new()));
+        let onrename = cx
+            .bump()
+            .alloc(cx.event_handler(move |event: Rename| seen.borrow_mut().push(event)));
+
+        let onchange = onrename.map(cx, |name: String| Rename { name });
+        onchange.call("bob".to_string());
+
+        assert_eq!(*seen.borrow(), [Rename { name: "bob".to_string() }]);
+
+        render! { "done" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+}
+
+#[test]
+fn once_only_calls_the_callback_once() {
+    fn app(cx: Scope) -> Element {
+        let calls: &Cell<u32> = cx.use_hook(|| Cell::new(0));
+        let handler = cx
+            .event_handler(move |_: ()| calls.set(calls.get() + 1))
+            .once();
+
+        handler.call(());
+        handler.call(());
+
+        assert_eq!(calls.get(), 1);
+        assert!(!handler.is_set());
+
+        render! { "done" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+}
+
+#[test]
+fn is_set_reports_whether_a_callback_is_present() {
+    fn app(cx: Scope) -> Element {
+        let handler = cx.event_handler(|_: ()| {});
+        assert!(handler.is_set());
+
+        handler.release();
+        assert!(!handler.is_set());
+
+        assert!(!EventHandler::<()>::noop().is_set());
+
+        render! { "done" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+}
+
+#[test]
+fn handlers_know_where_they_were_created() {
+    fn app(cx: Scope) -> Element {
+        let (handler, line) = (cx.event_handler(|_: ()| {}), line!());
+        assert_eq!(handler.origin(), cx.scope_id());
+
+        if cfg!(debug_assertions) {
+            let location = handler.created_at().unwrap();
+            assert_eq!(location.file(), file!());
+            assert_eq!(location.line(), line);
+        } else {
+            assert!(handler.created_at().is_none());
+        }
+
+        assert!(EventHandler::<()>::noop().created_at().is_none());
+
+        render! { "done" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+}
+
+#[test]
+fn new_handlers_can_be_built_outside_components() {
+    let bump = dioxus_core::exports::bumpalo::Bump::new();
+    let seen = RefCell::new(Vec::new());
+
+    let handler = EventHandler::new(&bump, |event: u32| seen.borrow_mut().push(event));
+    assert!(handler.is_set());
+    handler.call(1);
+    handler.call(2);
+    drop(handler);
+
+    assert_eq!(seen.into_inner(), [1, 2]);
+}
+
+#[test]
+fn debug_shows_whether_a_callback_is_set() {
+    let bump = dioxus_core::exports::bumpalo::Bump::new();
+    let handler = EventHandler::new(&bump, |_: ()| {});
+    assert_eq!(format!("{handler:?}"), "EventHandler { set: true }");
+
+    handler.release();
+    assert_eq!(format!("{handler:?}"), "EventHandler { set: false }");
+    assert_eq!(
+        format!("{:?}", EventHandler::<()>::noop()),
+        "EventHandler { set: false }"
+    );
+}
+
+static SPAWNED: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(not(miri))]
+#[tokio::test]
+async fn spawning_spawns_a_task_per_event() {
+    fn app(cx: Scope) -> Element {
+        let handler = EventHandler::spawning(cx, |amount: usize| async move {
+            SPAWNED.fetch_add(amount, Ordering::Relaxed);
+        });
+
+        cx.use_hook(|| {
+            handler.call(1);
+            handler.call(2);
+        });
+
+        render! { "done" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    tokio::select! {
+        _ = dom.wait_for_work() => {}
+        _ = tokio::time::sleep(Duration::from_millis(100)) => {}
+    };
+
+    assert_eq!(SPAWNED.load(Ordering::Relaxed), 3);
+}
+
+static CONFIRMED: AtomicUsize = AtomicUsize::new(0);
+static UNANSWERED: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(not(miri))]
+#[tokio::test]
+async fn request_resolves_to_the_response() {
+    fn app(cx: Scope) -> Element {
+        // A mock parent that confirms even lengths and drops the other requests unanswered.
+        let handler = cx.event_handler(|request: EventRequest<usize, bool>| {
+            if request.event() % 2 == 0 {
+                request.respond(true);
+            }
+        });
+
+        cx.use_hook(|| {
+            for len in [2, 3] {
+                let response = handler.request(len);
+                cx.spawn(async move {
+                    match response.await {
+                        Some(true) => CONFIRMED.fetch_add(1, Ordering::Relaxed),
+                        _ => UNANSWERED.fetch_add(1, Ordering::Relaxed),
+                    };
+                });
+            }
+        });
+
+        render! { "done" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    tokio::select! {
+        _ = dom.wait_for_work() => {}
+        _ = tokio::time::sleep(Duration::from_millis(100)) => {}
+    };
+
+    assert_eq!(CONFIRMED.load(Ordering::Relaxed), 1);
+    assert_eq!(UNANSWERED.load(Ordering::Relaxed), 1);
+}
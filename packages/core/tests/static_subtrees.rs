@@ -0,0 +1,61 @@
+//! Tests for telling apart template subtrees that never change from ones with dynamic parts.
+
+use dioxus::prelude::*;
+
+#[test]
+fn static_subtrees_are_static() {
+    fn app(cx: Scope) -> Element {
+        let node = render! {
+            div { class: "card",
+                h1 { "Title" }
+                p { "Body" }
+            }
+        }
+        .unwrap();
+
+        let roots = node.template.get().roots;
+        assert_eq!(roots.len(), 1);
+        assert!(roots[0].is_static());
+
+        None
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+}
+
+#[test]
+fn dynamic_parts_make_a_subtree_dynamic() {
+    fn app(cx: Scope) -> Element {
+        let size = 12;
+        let node = render! {
+            div { class: "card",
+                h1 { class: "size-{size}", "Title" }
+                p { "Body" }
+            }
+            span { onclick: |_| {}, "Static text, but a listener" }
+            div { "{size}" }
+        }
+        .unwrap();
+
+        let roots = node.template.get().roots;
+        assert_eq!(roots.len(), 3);
+        // A dynamic attribute on a nested element makes the whole root dynamic.
+        assert!(!roots[0].is_static());
+        let TemplateNode::Element { children, .. } = &roots[0] else {
+            panic!("expected an element root");
+        };
+        assert!(!children[0].is_static());
+        assert!(children[1].is_static());
+
+        // Listeners are dynamic attributes.
+        assert!(!roots[1].is_static());
+        // So are formatted children.
+        assert!(!roots[2].is_static());
+
+        None
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+}
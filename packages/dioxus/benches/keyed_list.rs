@@ -0,0 +1,87 @@
+//! Renders a keyed list of 1_000 todo items from a single component, where the classes of every item are formatted
+//! from a couple of values, like in todomvc.
+//!
+//! The list is rendered twice: once with the formatted classes allocated per attribute, which is what `rsx!` does,
+//! and once with them interned through `ScopeState::intern_text`, so identical classes are only allocated once per
+//! render. The bump usage of one render of each is printed before the timings.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use dioxus::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+criterion_group!(mbenches, keyed_list);
+criterion_main!(mbenches);
+
+static RAW_BUMP_BYTES: AtomicUsize = AtomicUsize::new(0);
+static INTERNED_BUMP_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+fn keyed_list(c: &mut Criterion) {
+    fn raw(cx: Scope) -> Element {
+        let list = render! {
+            ul {
+                (0..1_000_usize).map(|id| {
+                    let completed = id % 3 == 0;
+                    let state = if completed { "completed" } else { "active" };
+                    rsx! {
+                        li { key: "{id}", class: "todo {state}",
+                            input { class: "toggle toggle-{state}", r#type: "checkbox", checked: completed }
+                            label { "item {id}" }
+                        }
+                    }
+                })
+            }
+        };
+
+        RAW_BUMP_BYTES.store(cx.bump().allocated_bytes(), Ordering::Relaxed);
+        list
+    }
+
+    fn interned(cx: Scope) -> Element {
+        let list = render! {
+            ul {
+                (0..1_000_usize).map(|id| {
+                    let completed = id % 3 == 0;
+                    let state = if completed { "completed" } else { "active" };
+                    rsx! {
+                        li { key: "{id}", class: cx.intern_text(format_args!("todo {state}")),
+                            input {
+                                class: cx.intern_text(format_args!("toggle toggle-{state}")),
+                                r#type: "checkbox",
+                                checked: completed
+                            }
+                            label { "item {id}" }
+                        }
+                    }
+                })
+            }
+        };
+
+        INTERNED_BUMP_BYTES.store(cx.bump().allocated_bytes(), Ordering::Relaxed);
+        list
+    }
+
+    let mut raw_dom = VirtualDom::new(raw);
+    let _ = raw_dom.rebuild();
+    let mut interned_dom = VirtualDom::new(interned);
+    let _ = interned_dom.rebuild();
+    println!(
+        "bump bytes allocated by one render: {} per attribute, {} interned",
+        RAW_BUMP_BYTES.load(Ordering::Relaxed),
+        INTERNED_BUMP_BYTES.load(Ordering::Relaxed)
+    );
+
+    let mut group = c.benchmark_group("keyed list");
+    group.bench_function("per attribute", |b| {
+        b.iter(|| {
+            let g = raw_dom.rebuild();
+            assert!(g.edits.len() > 1);
+        })
+    });
+    group.bench_function("interned", |b| {
+        b.iter(|| {
+            let g = interned_dom.rebuild();
+            assert!(g.edits.len() > 1);
+        })
+    });
+    group.finish();
+}
@@ -21,6 +21,9 @@ pub use dioxus_rsx as rsx;
 #[cfg(feature = "macro")]
 pub use dioxus_core_macro as core_macro;
 
+#[cfg(feature = "ssr")]
+pub use dioxus_ssr as ssr;
+
 pub mod prelude {
     #[cfg(feature = "hooks")]
     pub use crate::hooks::*;
@@ -7,14 +7,14 @@
 //! - [x] Fields
 //! - [x] Componentbuilder synax
 //! - [x] Optional commas
-//! - [ ] Children
+//! - [x] Children
 //! - [ ] Keys
 //! - [ ] Properties spreading with with `..` syntax
 
 use super::*;
 
 use proc_macro2::TokenStream as TokenStream2;
-use quote::{quote, ToTokens, TokenStreamExt};
+use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
 use syn::{
     ext::IdentExt,
     parse::{Parse, ParseBuffer, ParseStream},
@@ -167,8 +167,12 @@ impl ToTokens for Component {
                         location: None,
                     };
 
+                    // Point at the nested content, so a component without a `children` prop reports
+                    // the missing `children` method there instead of on the whole component.
+                    let children = Ident::new("children", self.children[0].span());
+
                     toks.append_all(quote! {
-                        .children(
+                        .#children(
                             Some({ #renderer })
                         )
                     });
@@ -219,7 +223,8 @@ impl ToTokens for ContentField {
             ContentField::Formatted(s) => tokens.append_all(quote! {
                 __cx.raw_text(#s)
             }),
-            ContentField::OnHandlerRaw(e) => tokens.append_all(quote! {
+            // Spanned to the handler, so `EventHandler::created_at` points at it instead of the `rsx!` call.
+            ContentField::OnHandlerRaw(e) => tokens.append_all(quote_spanned! {e.span()=>
                 __cx.event_handler(#e)
             }),
         }
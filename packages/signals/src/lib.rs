@@ -14,3 +14,5 @@ pub(crate) mod signal;
 pub use signal::*;
 mod dependency;
 pub use dependency::*;
+mod maybe_signal;
+pub use maybe_signal::*;
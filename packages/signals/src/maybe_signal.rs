@@ -0,0 +1,73 @@
+use dioxus_core::FromReactive;
+
+use crate::{ReadOnlySignal, Signal};
+
+/// A prop that is either a plain value or a signal, for components that work with both.
+///
+/// Mark the prop with `#[props(reactive)]`, and its setter takes a `T`, a [`Signal<T>`] or a [`ReadOnlySignal<T>`]:
+///
+/// ```rust, ignore
+/// #[derive(Props, PartialEq)]
+/// struct CounterProps {
+///     #[props(reactive)]
+///     count: MaybeSignal<u32>,
+/// }
+///
+/// render! {
+///     Counter { count: 1 }
+///     Counter { count: signal }
+/// }
+/// ```
+pub enum MaybeSignal<T: 'static> {
+    /// A plain value, which never changes
+    Value(T),
+    /// A signal, which the reading component subscribes to
+    Signal(ReadOnlySignal<T>),
+}
+
+impl<T: 'static> MaybeSignal<T> {
+    /// Run a closure with a reference to the value. Reading a signal subscribes the current scope to it.
+    pub fn with<O>(&self, f: impl FnOnce(&T) -> O) -> O {
+        match self {
+            MaybeSignal::Value(value) => f(value),
+            MaybeSignal::Signal(signal) => signal.with(f),
+        }
+    }
+}
+
+impl<T: Clone + 'static> MaybeSignal<T> {
+    /// Get the current value. Reading a signal subscribes the current scope to it.
+    pub fn value(&self) -> T {
+        self.with(T::clone)
+    }
+}
+
+/// Plain values are compared by value and signals by identity, so a component only re-renders for a signal when
+/// it gets a different one.
+impl<T: PartialEq + 'static> PartialEq for MaybeSignal<T> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (MaybeSignal::Value(a), MaybeSignal::Value(b)) => a == b,
+            (MaybeSignal::Signal(a), MaybeSignal::Signal(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl<T: 'static> FromReactive<T> for MaybeSignal<T> {
+    fn from_reactive(value: T) -> Self {
+        MaybeSignal::Value(value)
+    }
+}
+
+impl<T: 'static> FromReactive<Signal<T>> for MaybeSignal<T> {
+    fn from_reactive(signal: Signal<T>) -> Self {
+        MaybeSignal::Signal(ReadOnlySignal::new(signal))
+    }
+}
+
+impl<T: 'static> FromReactive<ReadOnlySignal<T>> for MaybeSignal<T> {
+    fn from_reactive(signal: ReadOnlySignal<T>) -> Self {
+        MaybeSignal::Signal(signal)
+    }
+}
@@ -0,0 +1,29 @@
+#![allow(unused, non_upper_case_globals, non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_signals::*;
+
+#[derive(Props, PartialEq)]
+struct CounterProps {
+    #[props(reactive)]
+    count: MaybeSignal<u32>,
+}
+
+#[test]
+fn reactive_props_accept_values_and_signals() {
+    let mut dom = VirtualDom::new(|cx| {
+        let signal = use_signal(cx, || 2u32);
+
+        let plain = CounterProps::builder().count(1u32).build();
+        assert!(matches!(plain.count, MaybeSignal::Value(1)));
+        assert_eq!(plain.count.value(), 1);
+
+        let reactive = CounterProps::builder().count(signal).build();
+        assert!(matches!(reactive.count, MaybeSignal::Signal(_)));
+        assert_eq!(reactive.count.value(), 2);
+
+        render! { "done" }
+    });
+
+    let _ = dom.rebuild();
+}
@@ -1,7 +1,8 @@
 use dioxus_core::prelude::*;
 use std::fmt::Write;
 
-use crate::renderer::{str_truthy, BOOL_ATTRS};
+use crate::renderer::{merge_style, str_truthy, style_declarations};
+use dioxus_core::BOOL_ATTRS;
 
 #[derive(Debug)]
 pub struct StringCache {
@@ -87,7 +88,11 @@ impl StringCache {
                             if *name == "dangerous_inner_html" {
                                 inner_html = Some(value);
                             } else if let Some("style") = namespace {
-                                styles.push((name, value));
+                                merge_style(&mut styles, *name, *value);
+                            } else if *name == "style" && namespace.is_none() {
+                                for (name, value) in style_declarations(*value) {
+                                    merge_style(&mut styles, name, value);
+                                }
                             } else if BOOL_ATTRS.contains(name) {
                                 if str_truthy(value) {
                                     write!(chain, " {name}=\"{value}\"",)?;
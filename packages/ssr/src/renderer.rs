@@ -2,7 +2,7 @@ use super::cache::Segment;
 use crate::cache::StringCache;
 
 use dioxus_core::Attribute;
-use dioxus_core::{prelude::*, AttributeValue, DynamicNode, RenderReturn};
+use dioxus_core::{prelude::*, AttributeValue, DynamicNode, RenderReturn, BOOL_ATTRS};
 use std::collections::HashMap;
 use std::fmt::Write;
 use std::sync::Arc;
@@ -75,7 +75,7 @@ impl Renderer {
         let mut inner_html = None;
 
         // We need to keep track of the dynamic styles so we can insert them into the right place
-        let mut accumulated_dynamic_styles = Vec::new();
+        let mut accumulated_dynamic_styles: Vec<(&str, String)> = Vec::new();
 
         for segment in entry.segments.iter() {
             match segment {
@@ -84,8 +84,23 @@ impl Renderer {
                     if attr.name == "dangerous_inner_html" {
                         inner_html = Some(attr);
                     } else if attr.namespace == Some("style") {
-                        accumulated_dynamic_styles.push(attr);
-                    } else if BOOL_ATTRS.contains(&attr.name) {
+                        let mut value = String::new();
+                        write_value_unquoted(&mut value, &attr.value)?;
+                        merge_style(&mut accumulated_dynamic_styles, attr.name, value);
+                    } else if attr.name == "style" && attr.namespace.is_none() {
+                        // A raw style string is merged with the style properties instead of becoming a second attribute
+                        if let AttributeValue::Text(style) = &attr.value {
+                            for (name, value) in style_declarations(style) {
+                                merge_style(
+                                    &mut accumulated_dynamic_styles,
+                                    name,
+                                    value.to_string(),
+                                );
+                            }
+                        }
+                    } else if attr.is_boolean()
+                        || (attr.namespace.is_none() && BOOL_ATTRS.contains(&attr.name))
+                    {
                         if truthy(&attr.value) {
                             write!(buf, " {}=", attr.name)?;
                             write_value(buf, &attr.value)?;
@@ -149,10 +164,8 @@ impl Renderer {
                         if !*inside_style_tag {
                             write!(buf, " style=\"")?;
                         }
-                        for attr in &accumulated_dynamic_styles {
-                            write!(buf, "{}:", attr.name)?;
-                            write_value_unquoted(buf, &attr.value)?;
-                            write!(buf, ";")?;
+                        for (name, value) in &accumulated_dynamic_styles {
+                            write!(buf, "{name}:{value};")?;
                         }
                         if !*inside_style_tag {
                             write!(buf, "\"")?;
@@ -238,40 +251,58 @@ fn to_string_works() {
     assert_eq!(out, "<div class=\"asdasdasd\" class=\"asdasdasd\" id=\"id-123\">Hello world 1 --&gt;123&lt;-- Hello world 2<div>nest 1</div><div></div><div>nest 2</div>&lt;/diiiiiiiiv&gt;<div>finalize 0</div><div>finalize 1</div><div>finalize 2</div><div>finalize 3</div><div>finalize 4</div></div>");
 }
 
-pub(crate) const BOOL_ATTRS: &[&str] = &[
-    "allowfullscreen",
-    "allowpaymentrequest",
-    "async",
-    "autofocus",
-    "autoplay",
-    "checked",
-    "controls",
-    "default",
-    "defer",
-    "disabled",
-    "formnovalidate",
-    "hidden",
-    "ismap",
-    "itemscope",
-    "loop",
-    "multiple",
-    "muted",
-    "nomodule",
-    "novalidate",
-    "open",
-    "playsinline",
-    "readonly",
-    "required",
-    "reversed",
-    "selected",
-    "truespeed",
-    "webkitdirectory",
-];
-
 pub(crate) fn str_truthy(value: &str) -> bool {
     !value.is_empty() && value != "0" && value.to_lowercase() != "false"
 }
 
+/// Splits a raw `style` string like `"color: red; width: 10px"` into its property and value pairs
+///
+/// Only a `;` outside of parentheses and quotes ends a declaration, so values like
+/// `url(data:image/png;base64,...)` or `'a;b'` are kept whole.
+pub(crate) fn style_declarations(style: &str) -> impl Iterator<Item = (&str, &str)> {
+    let mut declarations = Vec::new();
+    let mut start = 0;
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut escaped = false;
+
+    for (i, c) in style.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+
+        match (quote, c) {
+            (_, '\\') => escaped = true,
+            (Some(open), c) if c == open => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth = depth.saturating_sub(1),
+            (None, ';') if depth == 0 => {
+                declarations.push(&style[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    declarations.push(&style[start..]);
+
+    declarations.into_iter().filter_map(|declaration| {
+        let (name, value) = declaration.split_once(':')?;
+        let name = name.trim();
+        (!name.is_empty()).then(|| (name, value.trim()))
+    })
+}
+
+/// Adds a style property to the group, overriding the value of an earlier entry for the same property
+pub(crate) fn merge_style<'a, V>(styles: &mut Vec<(&'a str, V)>, name: &'a str, value: V) {
+    match styles.iter_mut().find(|(existing, _)| *existing == name) {
+        Some((_, existing)) => *existing = value,
+        None => styles.push((name, value)),
+    }
+}
+
 pub(crate) fn truthy(value: &AttributeValue) -> bool {
     match value {
         AttributeValue::Text(value) => str_truthy(value),
@@ -1,3 +1,4 @@
+use dioxus::core::{Attribute, AttributeValue};
 use dioxus::prelude::*;
 
 #[test]
@@ -35,3 +36,26 @@ fn dynamic_boolean_attributs() {
         r#"<div></div><div hidden=true></div>"#
     );
 }
+
+#[test]
+fn hand_built_boolean_attributes() {
+    fn app(cx: Scope) -> Element {
+        let node = render! { div { hidden: false, autofocus: true } }.unwrap();
+
+        // `Attribute::new` doesn't mark the attribute as boolean, so the renderer has to go by its name.
+        let attrs = cx.bump().alloc([
+            Attribute::new("hidden", AttributeValue::Bool(false), None, false),
+            Attribute::new("autofocus", AttributeValue::Bool(true), None, false),
+        ]);
+
+        Some(VNode {
+            dynamic_attrs: &*attrs,
+            ..node
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    assert_eq!(dioxus_ssr::render(&dom), r#"<div autofocus=true></div>"#);
+}
@@ -0,0 +1,22 @@
+use dioxus::prelude::*;
+
+#[test]
+fn none_attributes_are_omitted() {
+    fn app(cx: Scope) -> Element {
+        let present: Option<&str> = Some("hello");
+        let missing: Option<&str> = None;
+
+        render! {
+            div { title: present }
+            div { title: missing }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        r#"<div title="hello"></div><div></div>"#
+    );
+}
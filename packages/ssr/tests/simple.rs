@@ -75,3 +75,67 @@ fn fragments() {
         "<div></div>"
     );
 }
+
+#[test]
+fn propless_component_render_to_string() {
+    #[component(marker)]
+    fn Greeting(cx: Scope) -> Element {
+        render! { p { "hello!" } }
+    }
+
+    assert_eq!(Greeting::render_to_string(), "<p>hello!</p>");
+}
+
+#[test]
+fn container_children_from_iterator() {
+    #[component]
+    fn List<'a>(cx: Scope<'a>, children: Element<'a>) -> Element {
+        render! { ul { children } }
+    }
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        render! {
+            List {
+                (0..3).map(|i| rsx! { li { "item {i}" } })
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        "<ul><li>item 0</li><li>item 1</li><li>item 2</li></ul>"
+    );
+}
+
+#[test]
+fn container_children_vec_from_iterator() {
+    #[component]
+    fn List<'a>(cx: Scope<'a>, children: Vec<Element<'a>>) -> Element {
+        render! {
+            ul {
+                children.iter().map(|child| rsx! { li { child } })
+            }
+        }
+    }
+
+    #[component]
+    fn App(cx: Scope) -> Element {
+        render! {
+            List {
+                children: (0..3).map(|i| render! { "item {i}" })
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    _ = dom.rebuild();
+
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        "<ul><li>item 0</li><li>item 1</li><li>item 2</li></ul>"
+    );
+}
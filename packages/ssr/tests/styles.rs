@@ -38,3 +38,50 @@ fn dynamic_styles() {
         r#"<div style="width:123px;"></div>"#
     );
 }
+
+#[test]
+fn raw_styles_merge_with_style_properties() {
+    fn app(cx: Scope) -> Element {
+        render! { div { style: "color: red; background: green", background: "blue" } }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        r#"<div style="color:red;background:blue;"></div>"#
+    );
+}
+
+#[test]
+fn dynamic_raw_styles_merge_with_style_properties() {
+    let color = "red";
+
+    assert_eq!(
+        dioxus_ssr::render_lazy(rsx! {
+            div { background: "{color}", style: "width: 10px; background: {color}" }
+        }),
+        r#"<div style="background:red;width:10px;"></div>"#
+    );
+}
+
+#[test]
+fn raw_styles_keep_semicolons_in_urls_and_strings() {
+    fn app(cx: Scope) -> Element {
+        render! {
+            div {
+                style: "background: url(data:image/png;base64,iVBORw0KGgo=); content: 'a;b'",
+                color: "red"
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    _ = dom.rebuild();
+
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        r#"<div style="background:url(data:image/png;base64,iVBORw0KGgo=);content:'a;b';color:red;"></div>"#
+    );
+}